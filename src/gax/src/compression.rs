@@ -0,0 +1,134 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-request compression selection.
+//!
+//! The reference gRPC clients expose `send_compressed` / `accept_compressed`
+//! knobs to negotiate gzip (or other) encodings. [Encoding] and
+//! [CompressionOptions] are the equivalent here: the wire encoding to send a
+//! request body with, and the encodings the caller is willing to accept a
+//! response body in.
+//!
+//! [RequestOptions][crate::options::RequestOptions] carries no compression
+//! selection; callers configure one at the point they build a compressing
+//! decorator (see each generated crate's `compression` module). Nothing in
+//! this crate sets encoding headers on a request or falls back to
+//! [Encoding::Identity] when a server rejects the requested encoding.
+
+/// A gRPC/HTTP content encoding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// No compression.
+    #[default]
+    Identity,
+    /// `gzip` compression.
+    Gzip,
+}
+
+impl Encoding {
+    /// The wire value used in `grpc-encoding` / `grpc-accept-encoding`
+    /// metadata, or the HTTP `Content-Encoding` / `Accept-Encoding` headers
+    /// for the REST transport.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encoding::Identity => "identity",
+            Encoding::Gzip => "gzip",
+        }
+    }
+}
+
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The compression a caller would like a request sent with, and a response
+/// accepted in.
+///
+/// `accept` may list more than one encoding, in preference order, the same
+/// way `grpc-accept-encoding` is a comma-separated list.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompressionOptions {
+    send: Encoding,
+    accept: Vec<Encoding>,
+}
+
+impl CompressionOptions {
+    /// Sets the encoding used for the outgoing request body.
+    pub fn with_send_compression(mut self, encoding: Encoding) -> Self {
+        self.send = encoding;
+        self
+    }
+
+    /// Adds `encoding` to the list of encodings the caller is willing to
+    /// accept a response body in, preferring encodings added earlier.
+    pub fn with_accept_compression(mut self, encoding: Encoding) -> Self {
+        self.accept.push(encoding);
+        self
+    }
+
+    /// The configured outgoing encoding.
+    pub fn send_compression(&self) -> Encoding {
+        self.send
+    }
+
+    /// The configured acceptable encodings, in preference order. Empty means
+    /// no preference was expressed, which transports should treat as
+    /// `[Encoding::Identity]`.
+    pub fn accept_compression(&self) -> &[Encoding] {
+        &self.accept
+    }
+
+    /// The `grpc-accept-encoding` metadata value for [accept_compression][Self::accept_compression],
+    /// or `None` if no preference was expressed.
+    pub fn accept_encoding_header(&self) -> Option<String> {
+        if self.accept.is_empty() {
+            return None;
+        }
+        Some(
+            self.accept
+                .iter()
+                .map(Encoding::as_str)
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_identity() {
+        let opts = CompressionOptions::default();
+        assert_eq!(opts.send_compression(), Encoding::Identity);
+        assert_eq!(opts.accept_compression(), &[]);
+        assert_eq!(opts.accept_encoding_header(), None);
+    }
+
+    #[test]
+    fn builds_accept_list_in_order() {
+        let opts = CompressionOptions::default()
+            .with_send_compression(Encoding::Gzip)
+            .with_accept_compression(Encoding::Gzip)
+            .with_accept_compression(Encoding::Identity);
+        assert_eq!(opts.send_compression(), Encoding::Gzip);
+        assert_eq!(
+            opts.accept_encoding_header().as_deref(),
+            Some("gzip,identity")
+        );
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The decision a [PollingPolicy][crate::polling_policy::PollingPolicy] makes
+//! after a failed poll attempt.
+
+/// What to do next after a poll attempt fails.
+#[derive(Debug)]
+pub enum LoopState {
+    /// Keep polling. Carries the error that triggered this decision, so a
+    /// caller that only sees the final state can still report what went
+    /// wrong along the way.
+    Continue(crate::error::Error),
+    /// Stop polling: the policy's own retry budget (elapsed time, attempt
+    /// count) is exhausted.
+    Exhausted(crate::error::Error),
+    /// Stop polling: the error is not retryable at all.
+    Permanent(crate::error::Error),
+}
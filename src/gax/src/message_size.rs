@@ -0,0 +1,167 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configurable max encode/decode message size limits.
+//!
+//! The reference clients expose `max_decoding_message_size` /
+//! `max_encoding_message_size` knobs on the gRPC channel, so a payload that
+//! would otherwise blow past the default 4MiB cap fails with a clear,
+//! actionable error instead of an opaque transport failure. [MessageSizeLimits]
+//! is the equivalent here.
+
+/// The default gRPC message size cap, matching the reference clients'
+/// default.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+/// Which direction a message was traveling when it exceeded its limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// The outgoing request body.
+    Encode,
+    /// The incoming response body.
+    Decode,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Encode => f.write_str("encoding"),
+            Direction::Decode => f.write_str("decoding"),
+        }
+    }
+}
+
+/// The max message sizes a client is willing to send or receive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageSizeLimits {
+    max_encoding_message_size: usize,
+    max_decoding_message_size: usize,
+}
+
+impl Default for MessageSizeLimits {
+    fn default() -> Self {
+        Self {
+            max_encoding_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            max_decoding_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+}
+
+impl MessageSizeLimits {
+    /// Sets the max size of an outgoing request body.
+    pub fn with_max_encoding_message_size(mut self, size: usize) -> Self {
+        self.max_encoding_message_size = size;
+        self
+    }
+
+    /// Sets the max size of an incoming response body.
+    pub fn with_max_decoding_message_size(mut self, size: usize) -> Self {
+        self.max_decoding_message_size = size;
+        self
+    }
+
+    /// The configured max size of an outgoing request body.
+    pub fn max_encoding_message_size(&self) -> usize {
+        self.max_encoding_message_size
+    }
+
+    /// The configured max size of an incoming response body.
+    pub fn max_decoding_message_size(&self) -> usize {
+        self.max_decoding_message_size
+    }
+
+    /// Returns a structured error naming `rpc`, `direction`, the configured
+    /// limit, and the actual size, if `actual` exceeds the limit for
+    /// `direction`.
+    pub fn check(
+        &self,
+        rpc: &'static str,
+        direction: Direction,
+        actual: usize,
+    ) -> Result<(), MessageTooLarge> {
+        let limit = match direction {
+            Direction::Encode => self.max_encoding_message_size,
+            Direction::Decode => self.max_decoding_message_size,
+        };
+        if actual > limit {
+            return Err(MessageTooLarge {
+                rpc,
+                direction,
+                limit,
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A message exceeded its configured [MessageSizeLimits].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageTooLarge {
+    rpc: &'static str,
+    direction: Direction,
+    limit: usize,
+    actual: usize,
+}
+
+impl std::fmt::Display for MessageTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} message for {} is {} bytes, exceeding the configured limit of {} bytes; \
+             raise the limit with MessageSizeLimits::with_max_{}_message_size if this is expected",
+            self.direction,
+            self.rpc,
+            self.actual,
+            self.limit,
+            match self.direction {
+                Direction::Encode => "encoding",
+                Direction::Decode => "decoding",
+            }
+        )
+    }
+}
+
+impl std::error::Error for MessageTooLarge {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_4mib_both_ways() {
+        let limits = MessageSizeLimits::default();
+        assert_eq!(limits.max_encoding_message_size(), DEFAULT_MAX_MESSAGE_SIZE);
+        assert_eq!(limits.max_decoding_message_size(), DEFAULT_MAX_MESSAGE_SIZE);
+    }
+
+    #[test]
+    fn check_passes_under_limit() {
+        let limits = MessageSizeLimits::default().with_max_decoding_message_size(100);
+        assert!(limits.check("Service.Method", Direction::Decode, 99).is_ok());
+        assert!(limits.check("Service.Method", Direction::Decode, 100).is_ok());
+    }
+
+    #[test]
+    fn check_fails_over_limit_with_names_rpc_and_limit() {
+        let limits = MessageSizeLimits::default().with_max_decoding_message_size(100);
+        let err = limits
+            .check("Service.Method", Direction::Decode, 101)
+            .unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("Service.Method"), "{msg}");
+        assert!(msg.contains("100"), "{msg}");
+        assert!(msg.contains("101"), "{msg}");
+    }
+}
@@ -30,25 +30,127 @@ pub mod internal {
     pub trait PageableResponse {
         type PageItem: Send;
 
+        /// The cursor used to resume pagination, per [AIP-4233](https://google.aip.dev/client-libraries/4233).
+        /// Most responses use the opaque page-token string described there,
+        /// in which case this can simply be set to `String`.
+        type PageCursor: Clone + Send;
+
         // Consumes the [PageableResponse] and returns the items associated with the
         // current page.
         fn items(self) -> Vec<Self::PageItem>;
 
-        /// Returns the next page token.
-        fn next_page_token(&self) -> String;
+        /// Returns the cursor needed to fetch the next page, or `None` once
+        /// pagination is exhausted.
+        fn next_cursor(&self) -> Option<Self::PageCursor>;
     }
 
-    /// Creates a new `impl Paginator<T, E>` given the initial page token and a function
-    /// to fetch the next response.
+    /// Creates a new `impl Paginator<T, E>` given the initial page cursor and a
+    /// function to fetch the next response.
     pub fn new_paginator<T, E, F>(
-        seed_token: String,
-        execute: impl Fn(String) -> F + Clone + Send + 'static,
+        seed: T::PageCursor,
+        execute: impl Fn(T::PageCursor) -> F + Clone + Send + 'static,
+    ) -> impl Paginator<T, E>
+    where
+        T: internal::PageableResponse,
+        F: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        PaginatorImpl::new(seed, execute)
+    }
+
+    /// Creates a new `impl Paginator<T, E>` that resumes iteration from a cursor
+    /// previously obtained from [super::Paginator::current_page_token].
+    ///
+    /// This is the same construction as [new_paginator], spelled out
+    /// separately so callers who persist a cursor (e.g. to resume a long
+    /// running listing across process restarts) have a name that documents
+    /// the intent at the call site.
+    pub fn resume_paginator<T, E, F>(
+        cursor: T::PageCursor,
+        execute: impl Fn(T::PageCursor) -> F + Clone + Send + 'static,
     ) -> impl Paginator<T, E>
     where
         T: internal::PageableResponse,
         F: Future<Output = Result<T, E>> + Send + 'static,
     {
-        PaginatorImpl::new(seed_token, execute)
+        PaginatorImpl::new(cursor, execute)
+    }
+
+    /// Creates a new `impl Paginator<T, E>` that prefetches up to `depth`
+    /// pages ahead of what the caller has consumed.
+    ///
+    /// As soon as a page's next page token is known, the next page is
+    /// fetched in the background rather than waiting for the caller to call
+    /// [super::Paginator::next] again. This trades extra concurrent RPCs for
+    /// lower end-to-end latency when a caller processes each page slowly.
+    /// Pages are always returned in the order they were requested, iteration
+    /// stops as soon as an empty page token or an error is observed, and
+    /// that first error is surfaced in stream order (i.e. after any earlier
+    /// pages already buffered ahead of it).
+    ///
+    /// `depth` is clamped to at least `1`, in which case this behaves like
+    /// [new_paginator] except that the next page is always requested
+    /// eagerly.
+    ///
+    /// This requires a Tokio runtime, since pages are fetched on a
+    /// background task so that they keep progressing while the caller is
+    /// still draining the previous page.
+    pub fn new_paginator_buffered<T, E, F>(
+        seed: T::PageCursor,
+        execute: impl Fn(T::PageCursor) -> F + Send + 'static,
+        depth: usize,
+    ) -> impl Paginator<T, E>
+    where
+        T: internal::PageableResponse + Send + 'static,
+        T::PageCursor: Send + 'static,
+        E: Send + 'static,
+        F: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        BufferedPaginatorImpl::new(seed, execute, depth)
+    }
+
+    /// What a [PagingErrorPolicy] decided to do about a page fetch error.
+    pub enum PageErrorAction {
+        /// Reissue the request for the same cursor after `after`, so long as
+        /// the policy's [max_attempts][PagingErrorPolicy::max_attempts] has
+        /// not been exceeded. Retries are transparent: no error is surfaced
+        /// to the caller unless attempts run out.
+        Retry { after: std::time::Duration },
+        /// Stop iteration, surfacing this error as the final item.
+        Terminate,
+        /// Surface this error to the caller, but leave the paginator
+        /// positioned so that a subsequent call to
+        /// [super::Paginator::next] retries the same cursor from scratch.
+        Skip,
+    }
+
+    /// Decides how a [super::Paginator] built by [new_paginator_with_policy]
+    /// should react to a page fetch error.
+    pub trait PagingErrorPolicy<E>: Send + Sync {
+        /// Classifies `error` into the action the paginator should take.
+        fn classify(&self, error: &E) -> PageErrorAction;
+
+        /// The maximum number of consecutive attempts for a single cursor
+        /// before a [PageErrorAction::Retry] is treated as a
+        /// [PageErrorAction::Terminate]. Defaults to `3`.
+        fn max_attempts(&self) -> u32 {
+            3
+        }
+    }
+
+    /// Creates a new `impl Paginator<T, E>` whose reaction to page fetch
+    /// errors is governed by `policy`, rather than unconditionally
+    /// terminating on the first error.
+    pub fn new_paginator_with_policy<T, E, F, P>(
+        seed: T::PageCursor,
+        execute: impl Fn(T::PageCursor) -> F + Clone + Send + 'static,
+        policy: P,
+    ) -> impl Paginator<T, E>
+    where
+        T: internal::PageableResponse,
+        F: Future<Output = Result<T, E>> + Send + 'static,
+        P: PagingErrorPolicy<E> + 'static,
+    {
+        PaginatorImpl::new_with_policy(seed, execute, policy)
     }
 }
 
@@ -68,6 +170,79 @@ where
     /// Returns the next mutation of the wrapped stream.
     fn next(&mut self) -> impl Future<Output = Option<Result<T, E>>> + Send;
 
+    /// Returns the cursor needed to fetch the next page, or `None` if
+    /// the paginator is exhausted or stopped on an error. Before the first
+    /// request this is the seed cursor.
+    ///
+    /// Callers can persist this value and later hand it to
+    /// [internal::resume_paginator] to continue iteration, e.g. across a
+    /// process restart.
+    fn current_page_token(&self) -> Option<T::PageCursor>;
+
+    /// Caps iteration at the first `max` pages, without issuing a request
+    /// for the page that would follow the limit.
+    fn take_pages(self, max: usize) -> impl Paginator<T, E>
+    where
+        Self: Sized,
+    {
+        TakePagesImpl {
+            inner: self,
+            remaining: max,
+        }
+    }
+
+    /// Returns the next page, or `None` if the paginator is exhausted,
+    /// short-circuiting on the first error.
+    ///
+    /// This is a thin wrapper over [next][Self::next] for callers who only
+    /// care about whether iteration is done, not about distinguishing "done"
+    /// from "errored".
+    fn try_next(&mut self) -> impl Future<Output = Result<Option<T>, E>> + Send {
+        async move {
+            match self.next().await {
+                Some(Ok(page)) => Ok(Some(page)),
+                Some(Err(e)) => Err(e),
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Drains the paginator into `C`, short-circuiting on the first `Err`.
+    ///
+    /// This gives callers the common "gather every page into a `Vec`" path
+    /// on stable Rust, without enabling the `unstable-stream` feature.
+    fn try_collect<C>(self) -> impl Future<Output = Result<C, E>> + Send
+    where
+        Self: Sized,
+        C: Default + Extend<T> + Send,
+    {
+        async move {
+            let mut paginator = self;
+            let mut out = C::default();
+            while let Some(page) = paginator.try_next().await? {
+                out.extend(std::iter::once(page));
+            }
+            Ok(out)
+        }
+    }
+
+    /// Drains the paginator into `C`, preserving each page's `Result` rather
+    /// than short-circuiting on the first `Err`.
+    fn collect<C>(self) -> impl Future<Output = C> + Send
+    where
+        Self: Sized,
+        C: Default + Extend<Result<T, E>> + Send,
+    {
+        async move {
+            let mut paginator = self;
+            let mut out = C::default();
+            while let Some(page) = paginator.next().await {
+                out.extend(std::iter::once(page));
+            }
+            out
+        }
+    }
+
     #[cfg(feature = "unstable-stream")]
     /// Convert the paginator to a stream.
     ///
@@ -76,49 +251,130 @@ where
 }
 
 #[pin_project]
-struct PaginatorImpl<T, E> {
+struct PaginatorImpl<T, E>
+where
+    T: internal::PageableResponse,
+{
     #[pin]
     stream: Pin<Box<dyn Stream<Item = Result<T, E>> + Send>>,
+    current_page_token: std::sync::Arc<std::sync::Mutex<Option<T::PageCursor>>>,
 }
 
-type ControlFlow = std::ops::ControlFlow<(), String>;
+type ControlFlowOf<C> = std::ops::ControlFlow<(), C>;
 
 impl<T, E> PaginatorImpl<T, E>
 where
     T: internal::PageableResponse,
 {
-    /// Creates a new [Paginator] given the initial page token and a function
+    /// Creates a new [Paginator] given the initial page cursor and a function
     /// to fetch the next response.
     pub fn new<F>(
-        seed_token: String,
-        execute: impl Fn(String) -> F + Clone + Send + 'static,
+        seed: T::PageCursor,
+        execute: impl Fn(T::PageCursor) -> F + Clone + Send + 'static,
     ) -> Self
     where
         F: Future<Output = Result<T, E>> + Send + 'static,
     {
-        let stream = unfold(ControlFlow::Continue(seed_token), move |state| {
+        let current_page_token = std::sync::Arc::new(std::sync::Mutex::new(Some(seed.clone())));
+        let state_token = current_page_token.clone();
+        let stream = unfold(ControlFlowOf::Continue(seed), move |state| {
             let execute = execute.clone();
+            let current_page_token = state_token.clone();
             async move {
-                let token = match state {
-                    ControlFlow::Continue(token) => token,
-                    ControlFlow::Break(_) => return None,
+                let cursor = match state {
+                    ControlFlowOf::Continue(cursor) => cursor,
+                    ControlFlowOf::Break(_) => {
+                        *current_page_token.lock().unwrap() = None;
+                        return None;
+                    }
                 };
-                match execute(token).await {
+                match execute(cursor).await {
                     Ok(page_resp) => {
-                        let tok = page_resp.next_page_token();
-                        let next_state = if tok.is_empty() {
-                            ControlFlow::Break(())
-                        } else {
-                            ControlFlow::Continue(tok)
+                        let next = page_resp.next_cursor();
+                        let next_state = match next.clone() {
+                            Some(cursor) => ControlFlowOf::Continue(cursor),
+                            None => ControlFlowOf::Break(()),
                         };
+                        *current_page_token.lock().unwrap() = next;
                         Some((Ok(page_resp), next_state))
                     }
-                    Err(e) => Some((Err(e), ControlFlow::Break(()))),
+                    Err(e) => {
+                        *current_page_token.lock().unwrap() = None;
+                        Some((Err(e), ControlFlowOf::Break(())))
+                    }
+                }
+            }
+        });
+        Self {
+            stream: Box::pin(stream),
+            current_page_token,
+        }
+    }
+
+    /// Creates a new [Paginator] whose reaction to page fetch errors is
+    /// governed by `policy`. See [internal::new_paginator_with_policy].
+    pub fn new_with_policy<F, P>(
+        seed: T::PageCursor,
+        execute: impl Fn(T::PageCursor) -> F + Clone + Send + 'static,
+        policy: P,
+    ) -> Self
+    where
+        F: Future<Output = Result<T, E>> + Send + 'static,
+        P: internal::PagingErrorPolicy<E> + 'static,
+    {
+        let policy: std::sync::Arc<dyn internal::PagingErrorPolicy<E>> = std::sync::Arc::new(policy);
+        let current_page_token = std::sync::Arc::new(std::sync::Mutex::new(Some(seed.clone())));
+        let state_token = current_page_token.clone();
+        let stream = unfold(ControlFlowOf::Continue(seed), move |state| {
+            let execute = execute.clone();
+            let policy = policy.clone();
+            let current_page_token = state_token.clone();
+            async move {
+                let cursor = match state {
+                    ControlFlowOf::Continue(cursor) => cursor,
+                    ControlFlowOf::Break(_) => {
+                        *current_page_token.lock().unwrap() = None;
+                        return None;
+                    }
+                };
+                let mut attempts: u32 = 0;
+                loop {
+                    match execute(cursor.clone()).await {
+                        Ok(page_resp) => {
+                            let next = page_resp.next_cursor();
+                            let next_state = match next.clone() {
+                                Some(cursor) => ControlFlowOf::Continue(cursor),
+                                None => ControlFlowOf::Break(()),
+                            };
+                            *current_page_token.lock().unwrap() = next;
+                            return Some((Ok(page_resp), next_state));
+                        }
+                        Err(e) => {
+                            attempts += 1;
+                            let action = policy.classify(&e);
+                            match action {
+                                internal::PageErrorAction::Retry { after }
+                                    if attempts < policy.max_attempts() =>
+                                {
+                                    tokio::time::sleep(after).await;
+                                    continue;
+                                }
+                                internal::PageErrorAction::Skip => {
+                                    return Some((Err(e), ControlFlowOf::Continue(cursor)));
+                                }
+                                _ => {
+                                    *current_page_token.lock().unwrap() = None;
+                                    return Some((Err(e), ControlFlowOf::Break(())));
+                                }
+                            }
+                        }
+                    }
                 }
             }
         });
         Self {
             stream: Box::pin(stream),
+            current_page_token,
         }
     }
 }
@@ -137,6 +393,10 @@ where
         self.stream.next().await
     }
 
+    fn current_page_token(&self) -> Option<T::PageCursor> {
+        self.current_page_token.lock().unwrap().clone()
+    }
+
     #[cfg(feature = "unstable-stream")]
     /// Convert the paginator to a stream.
     ///
@@ -155,12 +415,124 @@ where
 
 impl<T, E> sealed::Paginator for PaginatorImpl<T, E> where T: internal::PageableResponse {}
 
-impl<T, E> std::fmt::Debug for PaginatorImpl<T, E> {
+impl<T, E> std::fmt::Debug for PaginatorImpl<T, E>
+where
+    T: internal::PageableResponse,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Paginator").finish()
     }
 }
 
+/// A [Paginator] that prefetches up to `depth` pages ahead of what the
+/// caller has consumed. See [internal::new_paginator_buffered].
+struct BufferedPaginatorImpl<T, E>
+where
+    T: internal::PageableResponse,
+{
+    rx: tokio::sync::mpsc::Receiver<Result<T, E>>,
+    current_page_token: std::sync::Arc<std::sync::Mutex<Option<T::PageCursor>>>,
+}
+
+impl<T, E> BufferedPaginatorImpl<T, E>
+where
+    T: internal::PageableResponse + Send + 'static,
+    T::PageCursor: Send + 'static,
+    E: Send + 'static,
+{
+    fn new<F>(
+        seed: T::PageCursor,
+        execute: impl Fn(T::PageCursor) -> F + Send + 'static,
+        depth: usize,
+    ) -> Self
+    where
+        F: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        let depth = depth.max(1);
+        let current_page_token = std::sync::Arc::new(std::sync::Mutex::new(Some(seed.clone())));
+        let (tx, rx) = tokio::sync::mpsc::channel(depth);
+        let producer_token = current_page_token.clone();
+        tokio::spawn(async move {
+            let mut cursor = Some(seed);
+            while let Some(c) = cursor.take() {
+                match execute(c).await {
+                    Ok(page) => {
+                        let next = page.next_cursor();
+                        *producer_token.lock().unwrap() = next.clone();
+                        if tx.send(Ok(page)).await.is_err() {
+                            return;
+                        }
+                        cursor = next;
+                    }
+                    Err(e) => {
+                        *producer_token.lock().unwrap() = None;
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                }
+            }
+        });
+        Self {
+            rx,
+            current_page_token,
+        }
+    }
+}
+
+impl<T, E> Paginator<T, E> for BufferedPaginatorImpl<T, E>
+where
+    T: internal::PageableResponse + Send + 'static,
+    T::PageCursor: Send + 'static,
+    E: Send + 'static,
+{
+    fn items(self) -> impl ItemPaginator<T, E> {
+        ItemPaginatorImpl::new(self)
+    }
+
+    async fn next(&mut self) -> Option<Result<T, E>> {
+        let item = self.rx.recv().await;
+        // Record the cursor after the page just handed to the caller. The
+        // prefetching task also records its own position as it fetches
+        // further pages, so this value may run ahead of the caller.
+        *self.current_page_token.lock().unwrap() = match &item {
+            Some(Ok(page)) => page.next_cursor(),
+            _ => None,
+        };
+        item
+    }
+
+    fn current_page_token(&self) -> Option<T::PageCursor> {
+        self.current_page_token.lock().unwrap().clone()
+    }
+
+    #[cfg(feature = "unstable-stream")]
+    fn into_stream(self) -> impl futures::Stream<Item = Result<T, E>> + Unpin {
+        Box::pin(unfold(Some(self), move |state| async move {
+            if let Some(mut paginator) = state {
+                if let Some(pr) = paginator.next().await {
+                    return Some((pr, Some(paginator)));
+                }
+            };
+            None
+        }))
+    }
+}
+
+impl<T, E> sealed::Paginator for BufferedPaginatorImpl<T, E> where
+    T: internal::PageableResponse + Send + 'static
+{
+}
+
+impl<T, E> std::fmt::Debug for BufferedPaginatorImpl<T, E>
+where
+    T: internal::PageableResponse,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BufferedPaginator").finish()
+    }
+}
+
+
 pub trait ItemPaginator<T, E>: Send + sealed::Paginator
 where
     T: internal::PageableResponse,
@@ -172,6 +544,66 @@ where
     /// [`futures::stream::Stream`]: https://docs.rs/futures/latest/futures/stream/trait.Stream.html
     fn next(&mut self) -> impl Future<Output = Option<Result<T::PageItem, E>>> + Send;
 
+    /// Caps iteration at the first `max` items, without issuing a request
+    /// for the page that would follow the limit.
+    fn take_items(self, max: usize) -> impl ItemPaginator<T, E>
+    where
+        Self: Sized,
+    {
+        TakeItemsImpl {
+            inner: self,
+            remaining: max,
+        }
+    }
+
+    /// Returns the next item, or `None` if the paginator is exhausted,
+    /// short-circuiting on the first error.
+    fn try_next(&mut self) -> impl Future<Output = Result<Option<T::PageItem>, E>> + Send {
+        async move {
+            match self.next().await {
+                Some(Ok(item)) => Ok(Some(item)),
+                Some(Err(e)) => Err(e),
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Drains the paginator into `C`, short-circuiting on the first `Err`.
+    ///
+    /// This gives callers the common "gather every item into a `Vec`" path
+    /// on stable Rust, without enabling the `unstable-stream` feature.
+    fn try_collect<C>(self) -> impl Future<Output = Result<C, E>> + Send
+    where
+        Self: Sized,
+        C: Default + Extend<T::PageItem> + Send,
+    {
+        async move {
+            let mut paginator = self;
+            let mut out = C::default();
+            while let Some(item) = paginator.try_next().await? {
+                out.extend(std::iter::once(item));
+            }
+            Ok(out)
+        }
+    }
+
+    /// Drains the paginator into `C`, preserving each item's `Result` rather
+    /// than short-circuiting on the first `Err`.
+    fn collect<C>(self) -> impl Future<Output = C> + Send
+    where
+        Self: Sized,
+        C: Default + Extend<Result<T::PageItem, E>> + Send,
+    {
+        async move {
+            let mut paginator = self;
+            let mut out = C::default();
+            while let Some(item) = paginator.next().await {
+                out.extend(std::iter::once(item));
+            }
+            out
+        }
+    }
+
     #[cfg(feature = "unstable-stream")]
     /// Convert the paginator to a stream.
     ///
@@ -179,33 +611,36 @@ where
     fn into_stream(self) -> impl futures::Stream<Item = Result<T::PageItem, E>> + Unpin;
 }
 
-/// An adapter that converts a [Paginator] into a stream of individual page
+/// An adapter that converts any [Paginator] into a stream of individual page
 /// items.
-#[pin_project]
-struct ItemPaginatorImpl<T, E>
+struct ItemPaginatorImpl<P, T, E>
 where
+    P: Paginator<T, E>,
     T: internal::PageableResponse,
 {
-    #[pin]
-    stream: PaginatorImpl<T, E>,
+    stream: P,
     current_items: Option<std::vec::IntoIter<T::PageItem>>,
+    _marker: std::marker::PhantomData<fn() -> E>,
 }
 
-impl<T, E> ItemPaginatorImpl<T, E>
+impl<P, T, E> ItemPaginatorImpl<P, T, E>
 where
+    P: Paginator<T, E>,
     T: internal::PageableResponse,
 {
     /// Creates a new [ItemPaginator] from an existing [Paginator].
-    fn new(paginator: PaginatorImpl<T, E>) -> Self {
+    fn new(paginator: P) -> Self {
         Self {
             stream: paginator,
             current_items: None,
+            _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<T, E> ItemPaginator<T, E> for ItemPaginatorImpl<T, E>
+impl<P, T, E> ItemPaginator<T, E> for ItemPaginatorImpl<P, T, E>
 where
+    P: Paginator<T, E>,
     T: internal::PageableResponse,
 {
     /// Returns the next mutation of the wrapped stream.
@@ -250,7 +685,99 @@ where
     }
 }
 
-impl<T, E> sealed::Paginator for ItemPaginatorImpl<T, E> where T: internal::PageableResponse {}
+impl<P, T, E> sealed::Paginator for ItemPaginatorImpl<P, T, E>
+where
+    P: Paginator<T, E>,
+    T: internal::PageableResponse,
+{
+}
+
+/// Caps a [Paginator] at the first `max` pages. See [Paginator::take_pages].
+struct TakePagesImpl<P> {
+    inner: P,
+    remaining: usize,
+}
+
+impl<P, T, E> Paginator<T, E> for TakePagesImpl<P>
+where
+    P: Paginator<T, E>,
+    T: internal::PageableResponse,
+{
+    fn items(self) -> impl ItemPaginator<T, E> {
+        ItemPaginatorImpl::new(self)
+    }
+
+    async fn next(&mut self) -> Option<Result<T, E>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.inner.next().await
+    }
+
+    fn current_page_token(&self) -> Option<T::PageCursor> {
+        self.inner.current_page_token()
+    }
+
+    #[cfg(feature = "unstable-stream")]
+    fn into_stream(self) -> impl futures::Stream<Item = Result<T, E>> + Unpin {
+        Box::pin(unfold(Some(self), move |state| async move {
+            if let Some(mut paginator) = state {
+                if let Some(pr) = paginator.next().await {
+                    return Some((pr, Some(paginator)));
+                }
+            };
+            None
+        }))
+    }
+}
+
+impl<P, T, E> sealed::Paginator for TakePagesImpl<P>
+where
+    P: Paginator<T, E>,
+    T: internal::PageableResponse,
+{
+}
+
+/// Caps an [ItemPaginator] at the first `max` items. See
+/// [ItemPaginator::take_items].
+struct TakeItemsImpl<P> {
+    inner: P,
+    remaining: usize,
+}
+
+impl<P, T, E> ItemPaginator<T, E> for TakeItemsImpl<P>
+where
+    P: ItemPaginator<T, E>,
+    T: internal::PageableResponse,
+{
+    async fn next(&mut self) -> Option<Result<T::PageItem, E>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.inner.next().await
+    }
+
+    #[cfg(feature = "unstable-stream")]
+    fn into_stream(self) -> impl Stream<Item = Result<T::PageItem, E>> + Unpin {
+        Box::pin(unfold(Some(self), move |state| async move {
+            if let Some(mut paginator) = state {
+                if let Some(pr) = paginator.next().await {
+                    return Some((pr, Some(paginator)));
+                }
+            };
+            None
+        }))
+    }
+}
+
+impl<P, T, E> sealed::Paginator for TakeItemsImpl<P>
+where
+    P: ItemPaginator<T, E>,
+    T: internal::PageableResponse,
+{
+}
 
 #[cfg(test)]
 mod tests {
@@ -276,13 +803,18 @@ mod tests {
 
     impl PageableResponse for TestResponse {
         type PageItem = PageItem;
+        type PageCursor = String;
 
         fn items(self) -> Vec<Self::PageItem> {
             self.items
         }
 
-        fn next_page_token(&self) -> String {
-            self.next_page_token.clone()
+        fn next_cursor(&self) -> Option<String> {
+            if self.next_page_token.is_empty() {
+                None
+            } else {
+                Some(self.next_page_token.clone())
+            }
         }
     }
 
@@ -478,6 +1010,392 @@ mod tests {
         assert_eq!(resps[1].items[0].name, "item3");
     }
 
+    #[tokio::test]
+    async fn test_paginator_try_collect() {
+        let responses = vec![
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item1".to_string(),
+                }],
+                next_page_token: "token1".to_string(),
+            },
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item2".to_string(),
+                }],
+                next_page_token: "".to_string(),
+            },
+        ];
+
+        let client = Client {
+            inner: Arc::new(InnerClient {
+                data: Arc::new(Mutex::new(responses)),
+            }),
+        };
+        let pages: Vec<TestResponse> = client
+            .list_rpc_stream(TestRequest::default())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_item_paginator_try_collect() {
+        let responses = vec![
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item1".to_string(),
+                }],
+                next_page_token: "token1".to_string(),
+            },
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item2".to_string(),
+                }],
+                next_page_token: "".to_string(),
+            },
+        ];
+
+        let client = Client {
+            inner: Arc::new(InnerClient {
+                data: Arc::new(Mutex::new(responses)),
+            }),
+        };
+        let items: Vec<PageItem> = client
+            .list_rpc_stream(TestRequest::default())
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_paginator_current_page_token_and_resume() {
+        let responses = vec![
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item1".to_string(),
+                }],
+                next_page_token: "token2".to_string(),
+            },
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item2".to_string(),
+                }],
+                next_page_token: "".to_string(),
+            },
+        ];
+        let data = Arc::new(Mutex::new(responses));
+        let execute = {
+            let data = data.clone();
+            move |_: String| {
+                let data = data.clone();
+                async move { Ok::<_, Box<dyn std::error::Error>>(data.lock().unwrap().remove(0)) }
+            }
+        };
+
+        let mut paginator = new_paginator("token1".to_string(), execute.clone());
+        assert_eq!(paginator.current_page_token(), Some("token1".to_string()));
+        paginator.next().await;
+        assert_eq!(paginator.current_page_token(), Some("token2".to_string()));
+
+        let mut resumed = resume_paginator(paginator.current_page_token().unwrap(), execute);
+        let resp = resumed.next().await.unwrap().unwrap();
+        assert_eq!(resp.items[0].name, "item2");
+        assert_eq!(resumed.current_page_token(), None);
+    }
+
+    #[tokio::test]
+    async fn test_paginator_try_next_propagates_error() {
+        let execute = |_| async { Err::<TestResponse, Box<dyn std::error::Error>>("err".into()) };
+        let mut paginator = new_paginator(String::new(), execute);
+        let err = paginator.try_next().await.unwrap_err();
+        assert_eq!(err.to_string(), "err");
+    }
+
+    #[tokio::test]
+    async fn test_paginator_buffered() {
+        let responses = vec![
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item1".to_string(),
+                }],
+                next_page_token: "token2".to_string(),
+            },
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item2".to_string(),
+                }],
+                next_page_token: "token3".to_string(),
+            },
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item3".to_string(),
+                }],
+                next_page_token: "".to_string(),
+            },
+        ];
+        let data = Arc::new(Mutex::new(VecDeque::from(responses)));
+        let execute = move |_: String| {
+            let data = data.clone();
+            async move { Ok::<_, Box<dyn std::error::Error>>(data.lock().unwrap().pop_front().unwrap()) }
+        };
+
+        let mut paginator = new_paginator_buffered("token1".to_string(), execute, 2);
+        let mut resps = vec![];
+        while let Some(resp) = paginator.next().await {
+            resps.push(resp.unwrap());
+        }
+        assert_eq!(resps.len(), 3);
+        assert_eq!(resps[0].items[0].name, "item1");
+        assert_eq!(resps[1].items[0].name, "item2");
+        assert_eq!(resps[2].items[0].name, "item3");
+    }
+
+    #[tokio::test]
+    async fn test_paginator_buffered_items() {
+        let responses = vec![
+            TestResponse {
+                items: vec![
+                    PageItem {
+                        name: "item1".to_string(),
+                    },
+                    PageItem {
+                        name: "item2".to_string(),
+                    },
+                ],
+                next_page_token: "token2".to_string(),
+            },
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item3".to_string(),
+                }],
+                next_page_token: "".to_string(),
+            },
+        ];
+        let data = Arc::new(Mutex::new(VecDeque::from(responses)));
+        let execute = move |_: String| {
+            let data = data.clone();
+            async move { Ok::<_, Box<dyn std::error::Error>>(data.lock().unwrap().pop_front().unwrap()) }
+        };
+
+        let items: Vec<PageItem> = new_paginator_buffered("token1".to_string(), execute, 4)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 3);
+    }
+
+    /// A response that paginates by numeric offset instead of an opaque
+    /// string token, to exercise [PageableResponse::PageCursor].
+    struct OffsetResponse {
+        items: Vec<PageItem>,
+        next_offset: Option<u32>,
+    }
+
+    impl PageableResponse for OffsetResponse {
+        type PageItem = PageItem;
+        type PageCursor = u32;
+
+        fn items(self) -> Vec<Self::PageItem> {
+            self.items
+        }
+
+        fn next_cursor(&self) -> Option<u32> {
+            self.next_offset
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginator_non_string_cursor() {
+        let responses = VecDeque::from(vec![
+            OffsetResponse {
+                items: vec![PageItem {
+                    name: "item1".to_string(),
+                }],
+                next_offset: Some(1),
+            },
+            OffsetResponse {
+                items: vec![PageItem {
+                    name: "item2".to_string(),
+                }],
+                next_offset: None,
+            },
+        ]);
+        let data = Arc::new(Mutex::new(responses));
+        let execute = move |offset: u32| {
+            let data = data.clone();
+            async move {
+                assert!(offset == 0 || offset == 1);
+                Ok::<_, Box<dyn std::error::Error>>(data.lock().unwrap().pop_front().unwrap())
+            }
+        };
+
+        let mut paginator = new_paginator(0u32, execute);
+        assert_eq!(paginator.current_page_token(), Some(0));
+        let mut resps = vec![];
+        while let Some(resp) = paginator.next().await {
+            resps.push(resp.unwrap());
+        }
+        assert_eq!(resps.len(), 2);
+        assert_eq!(paginator.current_page_token(), None);
+    }
+
+    struct FlakyThenOk;
+
+    impl PagingErrorPolicy<String> for FlakyThenOk {
+        fn classify(&self, _error: &String) -> PageErrorAction {
+            PageErrorAction::Retry {
+                after: std::time::Duration::from_millis(1),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginator_with_policy_retries_transparently() {
+        let attempts = Arc::new(Mutex::new(0u32));
+        let execute = move |_: String| {
+            let attempts = attempts.clone();
+            async move {
+                let mut n = attempts.lock().unwrap();
+                *n += 1;
+                if *n < 3 {
+                    Err("transient".to_string())
+                } else {
+                    Ok(TestResponse {
+                        items: vec![PageItem {
+                            name: "item1".to_string(),
+                        }],
+                        next_page_token: "".to_string(),
+                    })
+                }
+            }
+        };
+
+        let mut paginator = new_paginator_with_policy(String::new(), execute, FlakyThenOk);
+        let resp = paginator.next().await.unwrap().unwrap();
+        assert_eq!(resp.items[0].name, "item1");
+        assert!(paginator.next().await.is_none());
+    }
+
+    struct AlwaysSkip;
+
+    impl PagingErrorPolicy<String> for AlwaysSkip {
+        fn classify(&self, _error: &String) -> PageErrorAction {
+            PageErrorAction::Skip
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginator_with_policy_skip_resumes_same_cursor() {
+        let attempts = Arc::new(Mutex::new(0u32));
+        let execute = move |token: String| {
+            let attempts = attempts.clone();
+            async move {
+                assert_eq!(token, "seed");
+                let mut n = attempts.lock().unwrap();
+                *n += 1;
+                if *n <= 2 {
+                    Err("flaky".to_string())
+                } else {
+                    Ok(TestResponse {
+                        items: vec![],
+                        next_page_token: "".to_string(),
+                    })
+                }
+            }
+        };
+
+        let mut paginator = new_paginator_with_policy("seed".to_string(), execute, AlwaysSkip);
+        assert!(paginator.next().await.unwrap().is_err());
+        assert!(paginator.next().await.unwrap().is_err());
+        assert!(paginator.next().await.unwrap().is_ok());
+    }
+
+    struct AlwaysTerminate;
+
+    impl PagingErrorPolicy<String> for AlwaysTerminate {
+        fn classify(&self, _error: &String) -> PageErrorAction {
+            PageErrorAction::Terminate
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginator_with_policy_terminate() {
+        let execute = |_: String| async { Err::<TestResponse, String>("fatal".to_string()) };
+        let mut paginator = new_paginator_with_policy(String::new(), execute, AlwaysTerminate);
+        assert!(paginator.next().await.unwrap().is_err());
+        assert!(paginator.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_paginator_take_pages_stops_without_extra_request() {
+        let requests = Arc::new(Mutex::new(0u32));
+        let execute = {
+            let requests = requests.clone();
+            move |token: String| {
+                let requests = requests.clone();
+                async move {
+                    *requests.lock().unwrap() += 1;
+                    Ok::<_, Box<dyn std::error::Error>>(TestResponse {
+                        items: vec![PageItem { name: token.clone() }],
+                        next_page_token: format!("{token}x"),
+                    })
+                }
+            }
+        };
+
+        let mut paginator = new_paginator("a".to_string(), execute).take_pages(2);
+        let mut pages = vec![];
+        while let Some(resp) = paginator.next().await {
+            pages.push(resp.unwrap());
+        }
+        assert_eq!(pages.len(), 2);
+        // One request per returned page, and no extra request for the page
+        // that would have followed the limit.
+        assert_eq!(*requests.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_item_paginator_take_items_stops_without_extra_request() {
+        let requests = Arc::new(Mutex::new(0u32));
+        let execute = {
+            let requests = requests.clone();
+            move |token: String| {
+                let requests = requests.clone();
+                async move {
+                    *requests.lock().unwrap() += 1;
+                    Ok::<_, Box<dyn std::error::Error>>(TestResponse {
+                        items: vec![
+                            PageItem {
+                                name: format!("{token}-1"),
+                            },
+                            PageItem {
+                                name: format!("{token}-2"),
+                            },
+                        ],
+                        next_page_token: format!("{token}x"),
+                    })
+                }
+            }
+        };
+
+        let mut paginator = new_paginator("a".to_string(), execute)
+            .items()
+            .take_items(3);
+        let mut items = vec![];
+        while let Some(item) = paginator.next().await {
+            items.push(item.unwrap());
+        }
+        assert_eq!(items.len(), 3);
+        // The first two items come from the first page and the third from
+        // the second; no third page should be requested after that.
+        assert_eq!(*requests.lock().unwrap(), 2);
+    }
+
     #[cfg(feature = "unstable-stream")]
     #[tokio::test]
     async fn test_item_paginator_into_stream() {
@@ -0,0 +1,169 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! How long to wait between attempts while polling a long-running operation.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Decides how long to wait before the next poll of a long-running
+/// operation.
+///
+/// `loop_start` is the [Instant] the poll loop began, and `attempt_count` is
+/// the number of attempts made so far (so `1` before the first retry);
+/// implementations may use either, both, or neither to compute the wait.
+pub trait PollingBackoffPolicy: std::fmt::Debug + Send + Sync {
+    /// Returns how long to sleep after `attempt_count` attempts, before the
+    /// next one.
+    fn wait_period(&self, loop_start: Instant, attempt_count: u32) -> Duration;
+}
+
+/// A source of uniform randomness in `[0, 1)`.
+///
+/// [ExponentialBackoff] takes this as a trait object rather than calling
+/// `rand::random` directly so tests can inject a fixed sequence and assert
+/// on the exact delay chosen, instead of only a range.
+pub trait JitterSource: std::fmt::Debug + Send + Sync {
+    /// Returns a value in `[0, 1)`.
+    fn sample(&self) -> f64;
+}
+
+/// The default [JitterSource], backed by the thread-local RNG.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemJitter;
+
+impl JitterSource for SystemJitter {
+    fn sample(&self) -> f64 {
+        rand::random::<f64>()
+    }
+}
+
+/// A full-jitter truncated exponential backoff, following the pattern the
+/// GCP `object_store` client uses: the nominal delay grows geometrically
+/// with the attempt count, up to `max_delay`, and the actual wait is a
+/// uniformly random duration between zero and that nominal delay. Full
+/// jitter like this de-correlates concurrent pollers hammering the same
+/// operation, which a plain (non-jittered) exponential backoff does not.
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    jitter: Arc<dyn JitterSource>,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: Arc::new(SystemJitter),
+        }
+    }
+}
+
+impl ExponentialBackoff {
+    /// Returns a default [ExponentialBackoff].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the nominal delay before the first retry (`attempt_count == 1`).
+    pub fn with_initial_delay(mut self, v: Duration) -> Self {
+        self.initial_delay = v;
+        self
+    }
+
+    /// Sets the ceiling the nominal delay never exceeds, before jitter is
+    /// applied.
+    pub fn with_max_delay(mut self, v: Duration) -> Self {
+        self.max_delay = v;
+        self
+    }
+
+    /// Sets the multiplier applied to the nominal delay after each attempt.
+    pub fn with_multiplier(mut self, v: f64) -> Self {
+        self.multiplier = v;
+        self
+    }
+
+    /// Overrides the source of jitter, e.g. with a fixed sequence in tests.
+    pub fn with_jitter_source<T>(mut self, v: T) -> Self
+    where
+        T: JitterSource + 'static,
+    {
+        self.jitter = Arc::new(v);
+        self
+    }
+
+    /// The nominal delay for `attempt_count`, before jitter: `min(max_delay,
+    /// initial_delay * multiplier^(attempt_count-1))`.
+    fn nominal_delay(&self, attempt_count: u32) -> Duration {
+        let exponent = attempt_count.saturating_sub(1) as i32;
+        let scale = self.multiplier.powi(exponent).max(0.0);
+        self.initial_delay.mul_f64(scale).min(self.max_delay)
+    }
+}
+
+impl PollingBackoffPolicy for ExponentialBackoff {
+    fn wait_period(&self, _loop_start: Instant, attempt_count: u32) -> Duration {
+        self.nominal_delay(attempt_count)
+            .mul_f64(self.jitter.sample())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug)]
+    struct FixedJitter(f64);
+
+    impl JitterSource for FixedJitter {
+        fn sample(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn nominal_delay_grows_and_saturates() {
+        let backoff = ExponentialBackoff::new()
+            .with_initial_delay(Duration::from_millis(100))
+            .with_multiplier(2.0)
+            .with_max_delay(Duration::from_millis(350));
+        assert_eq!(backoff.nominal_delay(1), Duration::from_millis(100));
+        assert_eq!(backoff.nominal_delay(2), Duration::from_millis(200));
+        assert_eq!(backoff.nominal_delay(3), Duration::from_millis(350));
+        assert_eq!(backoff.nominal_delay(4), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn full_jitter_scales_nominal_delay() {
+        let backoff = ExponentialBackoff::new()
+            .with_initial_delay(Duration::from_millis(100))
+            .with_multiplier(2.0)
+            .with_max_delay(Duration::from_secs(60))
+            .with_jitter_source(FixedJitter(0.5));
+        let wait = backoff.wait_period(Instant::now(), 2);
+        assert_eq!(wait, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn zero_jitter_sample_waits_zero() {
+        let backoff = ExponentialBackoff::new().with_jitter_source(FixedJitter(0.0));
+        let wait = backoff.wait_period(Instant::now(), 1);
+        assert_eq!(wait, Duration::ZERO);
+    }
+}
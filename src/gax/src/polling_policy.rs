@@ -0,0 +1,32 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! What to do when polling a long-running operation hits an error.
+
+/// Decides whether a poll loop should keep going after `get_operation` (or
+/// equivalent) fails.
+///
+/// `loop_start` is the [Instant][std::time::Instant] the poll loop began,
+/// and `attempt_count` is the 1-based number of the attempt that just
+/// failed; implementations may use either, both, or neither in their
+/// decision.
+pub trait PollingPolicy: std::fmt::Debug + Send + Sync {
+    /// Decides what to do after `error`. See [crate::loop_state::LoopState].
+    fn on_error(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        error: crate::error::Error,
+    ) -> crate::loop_state::LoopState;
+}
@@ -0,0 +1,146 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Composable decorator [PollingPolicy]s that bound how long an inner policy
+//! is allowed to keep polling.
+//!
+//! An inner policy's own `on_error` may happily return
+//! [LoopState::Continue] forever; [LimitedElapsedTime] and
+//! [LimitedAttemptCount] cap that at a total wall-clock budget or a total
+//! attempt count, respectively, converting `Continue` into
+//! [LoopState::Exhausted] once the cap is hit. This mirrors the
+//! `retry_timeout` knob the GCP `object_store` client added for its retry
+//! loop, applied here to the polling loop instead.
+
+use crate::loop_state::LoopState;
+use crate::polling_policy::PollingPolicy;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Wraps an inner [PollingPolicy], turning [LoopState::Continue] into
+/// [LoopState::Exhausted] once `loop_start.elapsed() >= max_total`.
+#[derive(Clone, Debug)]
+pub struct LimitedElapsedTime {
+    inner: Arc<dyn PollingPolicy>,
+    max_total: Duration,
+}
+
+impl LimitedElapsedTime {
+    /// Wraps `inner`, capping it at `max_total` of total elapsed time.
+    pub fn new<T>(max_total: Duration, inner: T) -> Self
+    where
+        T: PollingPolicy + 'static,
+    {
+        Self {
+            inner: Arc::new(inner),
+            max_total,
+        }
+    }
+}
+
+impl PollingPolicy for LimitedElapsedTime {
+    fn on_error(
+        &self,
+        loop_start: Instant,
+        attempt_count: u32,
+        error: crate::error::Error,
+    ) -> LoopState {
+        match self.inner.on_error(loop_start, attempt_count, error) {
+            LoopState::Continue(error) if loop_start.elapsed() >= self.max_total => {
+                LoopState::Exhausted(error)
+            }
+            other => other,
+        }
+    }
+}
+
+/// Wraps an inner [PollingPolicy], turning [LoopState::Continue] into
+/// [LoopState::Exhausted] once `attempt_count` exceeds `max_attempts`.
+#[derive(Clone, Debug)]
+pub struct LimitedAttemptCount {
+    inner: Arc<dyn PollingPolicy>,
+    max_attempts: u32,
+}
+
+impl LimitedAttemptCount {
+    /// Wraps `inner`, stopping once a failed attempt's `attempt_count`
+    /// exceeds `max_attempts`.
+    pub fn new<T>(max_attempts: u32, inner: T) -> Self
+    where
+        T: PollingPolicy + 'static,
+    {
+        Self {
+            inner: Arc::new(inner),
+            max_attempts,
+        }
+    }
+}
+
+impl PollingPolicy for LimitedAttemptCount {
+    fn on_error(
+        &self,
+        loop_start: Instant,
+        attempt_count: u32,
+        error: crate::error::Error,
+    ) -> LoopState {
+        match self.inner.on_error(loop_start, attempt_count, error) {
+            LoopState::Continue(error) if attempt_count > self.max_attempts => {
+                LoopState::Exhausted(error)
+            }
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct AlwaysContinue;
+    impl PollingPolicy for AlwaysContinue {
+        fn on_error(
+            &self,
+            _loop_start: Instant,
+            _attempt_count: u32,
+            error: crate::error::Error,
+        ) -> LoopState {
+            LoopState::Continue(error)
+        }
+    }
+
+    #[test]
+    fn limited_attempt_count_exhausts_past_the_cap() {
+        let policy = LimitedAttemptCount::new(3, AlwaysContinue);
+        let loop_start = Instant::now();
+        assert!(matches!(
+            policy.on_error(loop_start, 3, crate::error::Error::other("x")),
+            LoopState::Continue(_)
+        ));
+        assert!(matches!(
+            policy.on_error(loop_start, 4, crate::error::Error::other("x")),
+            LoopState::Exhausted(_)
+        ));
+    }
+
+    #[test]
+    fn limited_elapsed_time_exhausts_past_the_cap() {
+        let policy = LimitedElapsedTime::new(Duration::ZERO, AlwaysContinue);
+        let loop_start = Instant::now();
+        assert!(matches!(
+            policy.on_error(loop_start, 1, crate::error::Error::other("x")),
+            LoopState::Exhausted(_)
+        ));
+    }
+}
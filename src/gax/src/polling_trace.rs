@@ -0,0 +1,81 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structured `tracing` instrumentation for long-running-operation polling
+//! loops.
+//!
+//! Every generated client's poller calls
+//! [get_polling_policy][crate::polling_policy::PollingPolicy] and
+//! [get_polling_backoff_policy][crate::polling_backoff_policy::PollingBackoffPolicy]
+//! in essentially the same loop; [trace_attempt] and [trace_decision] give
+//! operators visibility into that loop (attempt counts, chosen wait
+//! periods, why a policy eventually stopped) without each one
+//! reimplementing it. Both are no-ops unless the crate that calls them
+//! enables the `tracing-polling` feature, so a build that doesn't want the
+//! `tracing` dependency stays lean.
+
+/// Records one polling attempt: the operation's name, the 1-based attempt
+/// count, and the backoff `wait_period` chosen before making it.
+#[cfg(feature = "tracing-polling")]
+pub fn trace_attempt(operation_name: &str, attempt_count: u32, wait: std::time::Duration) {
+    tracing::trace!(
+        operation = operation_name,
+        attempt_count,
+        wait_ms = wait.as_millis() as u64,
+        "polling long-running operation"
+    );
+}
+
+/// No-op: the `tracing-polling` feature is disabled.
+#[cfg(not(feature = "tracing-polling"))]
+pub fn trace_attempt(_operation_name: &str, _attempt_count: u32, _wait: std::time::Duration) {}
+
+/// Records a [PollingPolicy][crate::polling_policy::PollingPolicy]'s
+/// decision after a failed poll attempt, and emits a `warn`-level event if
+/// that decision stops polling while the operation is still pending.
+#[cfg(feature = "tracing-polling")]
+pub fn trace_decision(
+    operation_name: &str,
+    attempt_count: u32,
+    decision: &crate::loop_state::LoopState,
+) {
+    let outcome = match decision {
+        crate::loop_state::LoopState::Continue(_) => "continue",
+        crate::loop_state::LoopState::Exhausted(_) => "exhausted",
+        crate::loop_state::LoopState::Permanent(_) => "permanent",
+    };
+    tracing::trace!(
+        operation = operation_name,
+        attempt_count,
+        outcome,
+        "polling policy decision"
+    );
+    if !matches!(decision, crate::loop_state::LoopState::Continue(_)) {
+        tracing::warn!(
+            operation = operation_name,
+            attempt_count,
+            outcome,
+            "polling policy stopped polling a still-pending long-running operation"
+        );
+    }
+}
+
+/// No-op: the `tracing-polling` feature is disabled.
+#[cfg(not(feature = "tracing-polling"))]
+pub fn trace_decision(
+    _operation_name: &str,
+    _attempt_count: u32,
+    _decision: &crate::loop_state::LoopState,
+) {
+}
@@ -0,0 +1,192 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stream-based long-running-operation polling, with backoff that resets
+//! on forward progress.
+//!
+//! Modeled on the kube-runtime `StreamBackoff` adapter: each call to
+//! `poll_once` represents one poll attempt. When an attempt succeeds but
+//! shows no forward progress (the operation is still pending, with the same
+//! metadata as last time), [stream_backoff] sleeps for
+//! [PollingBackoffPolicy::wait_period][crate::polling_backoff_policy::PollingBackoffPolicy::wait_period]
+//! before the next attempt, growing the wait the same way a plain retry loop
+//! would. When an attempt shows forward progress (updated metadata or
+//! percent-complete), the attempt count resets, so backoff does not grow
+//! unboundedly on an operation that is merely slow rather than stuck. When
+//! an attempt errors, [PollingPolicy::on_error][crate::polling_policy::PollingPolicy::on_error]
+//! decides whether to keep going or close the stream.
+
+use crate::loop_state::LoopState;
+use crate::polling_backoff_policy::PollingBackoffPolicy;
+use crate::polling_policy::PollingPolicy;
+use futures::Stream;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Implemented by the items [stream_backoff] yields, so it can tell whether
+/// an item represents forward progress (resetting backoff) or not.
+pub trait PollProgress {
+    /// Returns `true` if this item represents forward progress since the
+    /// last one (for example, updated metadata or a higher percent-complete)
+    /// and backoff should reset.
+    fn is_progress(&self) -> bool;
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Polling { attempt: u32 },
+    Done,
+}
+
+/// Repeatedly calls `poll_once`, applying `backoff_policy` between attempts
+/// that show no progress (resetting on ones that do) and `polling_policy`
+/// to decide whether an error is worth retrying. See the [module][self]
+/// docs.
+pub fn stream_backoff<F, Fut, T>(
+    polling_policy: Arc<dyn PollingPolicy>,
+    backoff_policy: Arc<dyn PollingBackoffPolicy>,
+    poll_once: F,
+) -> impl Stream<Item = crate::Result<T>>
+where
+    F: FnMut() -> Fut + Send + 'static,
+    Fut: Future<Output = crate::Result<T>> + Send + 'static,
+    T: PollProgress + Send + 'static,
+{
+    let loop_start = Instant::now();
+    futures::stream::unfold(
+        (State::Polling { attempt: 0 }, poll_once),
+        move |(state, mut poll_once)| {
+            let polling_policy = polling_policy.clone();
+            let backoff_policy = backoff_policy.clone();
+            async move {
+                let State::Polling { mut attempt } = state else {
+                    return None;
+                };
+                loop {
+                    if attempt > 0 {
+                        let wait = backoff_policy.wait_period(loop_start, attempt);
+                        tokio::time::sleep(wait).await;
+                    }
+                    match poll_once().await {
+                        Ok(item) => {
+                            let next_attempt = if item.is_progress() { 0 } else { attempt + 1 };
+                            return Some((
+                                Ok(item),
+                                (State::Polling { attempt: next_attempt }, poll_once),
+                            ));
+                        }
+                        Err(error) => match polling_policy.on_error(loop_start, attempt + 1, error)
+                        {
+                            LoopState::Continue(_) => {
+                                attempt += 1;
+                                continue;
+                            }
+                            LoopState::Exhausted(error) | LoopState::Permanent(error) => {
+                                return Some((Err(error), (State::Done, poll_once)));
+                            }
+                        },
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Debug)]
+    struct Item {
+        progress: bool,
+    }
+
+    impl PollProgress for Item {
+        fn is_progress(&self) -> bool {
+            self.progress
+        }
+    }
+
+    #[derive(Debug)]
+    struct AlwaysContinue;
+    impl PollingPolicy for AlwaysContinue {
+        fn on_error(
+            &self,
+            _loop_start: Instant,
+            _attempt_count: u32,
+            error: crate::error::Error,
+        ) -> LoopState {
+            LoopState::Continue(error)
+        }
+    }
+
+    #[derive(Debug)]
+    struct NoWait;
+    impl PollingBackoffPolicy for NoWait {
+        fn wait_period(&self, _loop_start: Instant, _attempt_count: u32) -> std::time::Duration {
+            std::time::Duration::ZERO
+        }
+    }
+
+    #[tokio::test]
+    async fn stops_after_three_items() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = calls.clone();
+        let stream = stream_backoff(
+            Arc::new(AlwaysContinue),
+            Arc::new(NoWait),
+            move || {
+                let n = calls_clone.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if n >= 2 {
+                        Ok(Item { progress: false })
+                    } else {
+                        Ok(Item { progress: true })
+                    }
+                }
+            },
+        );
+        let items: Vec<_> = stream.take(3).collect().await;
+        assert_eq!(items.len(), 3);
+        assert!(items.iter().all(|r| r.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn closes_on_permanent_error() {
+        let stream = stream_backoff(
+            Arc::new(TerminateImmediately),
+            Arc::new(NoWait),
+            move || async { Err(crate::error::Error::other("permanent")) },
+        );
+        let items: Vec<_> = stream.collect().await;
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+    }
+
+    #[derive(Debug)]
+    struct TerminateImmediately;
+    impl PollingPolicy for TerminateImmediately {
+        fn on_error(
+            &self,
+            _loop_start: Instant,
+            _attempt_count: u32,
+            error: crate::error::Error,
+        ) -> LoopState {
+            LoopState::Permanent(error)
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Classifying a [gax::error::Error][crate::error::Error] as transient
+//! (worth retrying) or permanent.
+//!
+//! [TestPollingPolicy] (see `gax/tests/http_client_polling.rs`) always
+//! returns [LoopState::Continue][crate::loop_state::LoopState::Continue], but
+//! a real policy needs to stop immediately on errors that retrying can never
+//! fix. [Aip194Strict] makes that distinction using the status codes AIP-194
+//! treats as safe to retry by default — `429`, `500`, `503`, `504`
+//! (`UNAVAILABLE`/`DEADLINE_EXCEEDED` in gRPC terms) — and treats everything
+//! else, notably `400` (`INVALID_ARGUMENT`), `403` (`PERMISSION_DENIED`), and
+//! `404`, as permanent.
+
+use crate::loop_state::LoopState;
+use crate::polling_policy::PollingPolicy;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Whether `status`, an HTTP status code, is one of the codes AIP-194
+/// treats as safe to retry by default.
+pub fn is_transient_status(status: u16) -> bool {
+    matches!(status, 429 | 500 | 503 | 504)
+}
+
+/// Decides whether an error is transient (worth retrying) or permanent.
+///
+/// Implement this to plug a custom classifier into [Aip194Strict] — for
+/// example to additionally retry a service-specific error code the default
+/// [DefaultTransientErrorPredicate] doesn't know about.
+pub trait TransientErrorPredicate: std::fmt::Debug + Send + Sync {
+    /// Returns `true` if `error` is transient and worth retrying.
+    fn is_transient(&self, error: &crate::error::Error) -> bool;
+}
+
+/// The default [TransientErrorPredicate]: transient if and only if
+/// [is_transient_status] says so for the error's HTTP status code. An error
+/// with no HTTP status code (e.g. a transport-level failure with no
+/// response at all) is treated as permanent.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultTransientErrorPredicate;
+
+impl TransientErrorPredicate for DefaultTransientErrorPredicate {
+    fn is_transient(&self, error: &crate::error::Error) -> bool {
+        error
+            .http_status_code()
+            .map(is_transient_status)
+            .unwrap_or(false)
+    }
+}
+
+/// A [PollingPolicy] that keeps polling on a transient error and stops
+/// immediately ([LoopState::Permanent]) on anything else. See the
+/// [module][self] docs.
+#[derive(Clone, Debug)]
+pub struct Aip194Strict {
+    predicate: Arc<dyn TransientErrorPredicate>,
+}
+
+impl Default for Aip194Strict {
+    fn default() -> Self {
+        Self {
+            predicate: Arc::new(DefaultTransientErrorPredicate),
+        }
+    }
+}
+
+impl Aip194Strict {
+    /// Returns an [Aip194Strict] using [DefaultTransientErrorPredicate].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the transient-error classification.
+    pub fn with_predicate<T>(mut self, predicate: T) -> Self
+    where
+        T: TransientErrorPredicate + 'static,
+    {
+        self.predicate = Arc::new(predicate);
+        self
+    }
+}
+
+impl PollingPolicy for Aip194Strict {
+    fn on_error(
+        &self,
+        _loop_start: Instant,
+        _attempt_count: u32,
+        error: crate::error::Error,
+    ) -> LoopState {
+        if self.predicate.is_transient(&error) {
+            LoopState::Continue(error)
+        } else {
+            LoopState::Permanent(error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_known_statuses() {
+        assert!(is_transient_status(429));
+        assert!(is_transient_status(500));
+        assert!(is_transient_status(503));
+        assert!(is_transient_status(504));
+        assert!(!is_transient_status(400));
+        assert!(!is_transient_status(403));
+        assert!(!is_transient_status(404));
+    }
+
+    #[derive(Debug)]
+    struct AlwaysTransient;
+    impl TransientErrorPredicate for AlwaysTransient {
+        fn is_transient(&self, _error: &crate::error::Error) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn custom_predicate_overrides_default() {
+        let policy = Aip194Strict::new().with_predicate(AlwaysTransient);
+        let loop_start = Instant::now();
+        let state = policy.on_error(loop_start, 1, crate::error::Error::other("whatever"));
+        assert!(matches!(state, LoopState::Continue(_)));
+    }
+}
@@ -0,0 +1,476 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [crate::traits::KeyManagementService] decorator that caches immutable
+//! reads.
+//!
+//! [get_public_key][crate::traits::KeyManagementService::get_public_key],
+//! [get_crypto_key_version][crate::traits::KeyManagementService::get_crypto_key_version],
+//! and [get_import_job][crate::traits::KeyManagementService::get_import_job]
+//! all return data that is effectively immutable for a given resource name:
+//! a public key never changes once its crypto key version exists, and a key
+//! version's import job doesn't change either. [Cached] memoizes those three
+//! calls by resource name so repeated lookups don't hit the network every
+//! time.
+//!
+//! The one field that *does* change over a crypto key version's lifetime is
+//! its state (`PENDING_GENERATION` -> `ENABLED` -> ... -> `DESTROYED`), so
+//! [Cached] invalidates the cached entry whenever
+//! `destroy_crypto_key_version`, `restore_crypto_key_version`, or
+//! `update_crypto_key_version` passes through it for the same name, rather
+//! than relying solely on the TTL to catch up.
+
+use crate::Result;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The default time-to-live for a cached entry.
+const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+/// The default maximum number of entries per cached resource type.
+const DEFAULT_MAX_CAPACITY: u64 = 10_000;
+
+/// A pluggable cache backend for a single resource type.
+///
+/// The default backend ([new_default_store]) is an in-memory, TTL-and-LRU
+/// store. Implement this trait to plug in something else, e.g. a shared
+/// cache backed by an external store, when running many client instances
+/// that should agree on what's cached.
+pub trait CacheStore<V>: Send + Sync
+where
+    V: Clone + Send + Sync + 'static,
+{
+    /// Returns the cached value for `key`, if present and not expired.
+    fn get(&self, key: &str) -> Option<V>;
+    /// Caches `value` under `key`.
+    fn put(&self, key: String, value: V);
+    /// Evicts `key`, if present.
+    fn invalidate(&self, key: &str);
+}
+
+/// The default [CacheStore]: an in-memory cache with both a maximum entry
+/// count (evicted least-recently-used) and a time-to-live.
+pub struct MokaStore<V>(moka::sync::Cache<String, V>)
+where
+    V: Clone + Send + Sync + 'static;
+
+impl<V> MokaStore<V>
+where
+    V: Clone + Send + Sync + 'static,
+{
+    pub fn new(max_capacity: u64, ttl: Duration) -> Self {
+        Self(
+            moka::sync::Cache::builder()
+                .max_capacity(max_capacity)
+                .time_to_live(ttl)
+                .build(),
+        )
+    }
+}
+
+impl<V> CacheStore<V> for MokaStore<V>
+where
+    V: Clone + Send + Sync + 'static,
+{
+    fn get(&self, key: &str) -> Option<V> {
+        self.0.get(key)
+    }
+
+    fn put(&self, key: String, value: V) {
+        self.0.insert(key, value);
+    }
+
+    fn invalidate(&self, key: &str) {
+        self.0.invalidate(key);
+    }
+}
+
+fn new_default_store<V>(ttl: Duration) -> Arc<dyn CacheStore<V>>
+where
+    V: Clone + Send + Sync + 'static,
+{
+    Arc::new(MokaStore::new(DEFAULT_MAX_CAPACITY, ttl))
+}
+
+/// Implements a [KeyManagementService](crate::traits::KeyManagementService)
+/// decorator that caches `get_public_key`, `get_crypto_key_version`, and
+/// `get_import_job`, invalidating on the calls that can change a crypto key
+/// version's state.
+///
+/// See the [module][self] documentation for what is cached and why.
+pub struct Cached<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    public_keys: Arc<dyn CacheStore<crate::model::PublicKey>>,
+    crypto_key_versions: Arc<dyn CacheStore<crate::model::CryptoKeyVersion>>,
+    import_jobs: Arc<dyn CacheStore<crate::model::ImportJob>>,
+}
+
+impl<T> std::fmt::Debug for Cached<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cached").field("inner", &self.inner).finish()
+    }
+}
+
+impl<T> Cached<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    /// Creates a new decorator using the default in-memory store with a
+    /// five minute TTL for every cached resource type.
+    pub fn new(inner: T) -> Self {
+        Self::with_ttl(inner, DEFAULT_TTL)
+    }
+
+    /// Creates a new decorator using the default in-memory store with the
+    /// given TTL for every cached resource type.
+    pub fn with_ttl(inner: T, ttl: Duration) -> Self {
+        Self {
+            inner,
+            public_keys: new_default_store(ttl),
+            crypto_key_versions: new_default_store(ttl),
+            import_jobs: new_default_store(ttl),
+        }
+    }
+
+    /// Creates a new decorator with an explicit [CacheStore] per cached
+    /// resource type, for callers that want a backend other than the
+    /// default in-memory store.
+    pub fn with_stores(
+        inner: T,
+        public_keys: Arc<dyn CacheStore<crate::model::PublicKey>>,
+        crypto_key_versions: Arc<dyn CacheStore<crate::model::CryptoKeyVersion>>,
+        import_jobs: Arc<dyn CacheStore<crate::model::ImportJob>>,
+    ) -> Self {
+        Self {
+            inner,
+            public_keys,
+            crypto_key_versions,
+            import_jobs,
+        }
+    }
+}
+
+impl<T> crate::traits::KeyManagementService for Cached<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    async fn list_key_rings(
+        &self,
+        req: crate::model::ListKeyRingsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListKeyRingsResponse> {
+        self.inner.list_key_rings(req, options).await
+    }
+
+    async fn list_crypto_keys(
+        &self,
+        req: crate::model::ListCryptoKeysRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeysResponse> {
+        self.inner.list_crypto_keys(req, options).await
+    }
+
+    async fn list_crypto_key_versions(
+        &self,
+        req: crate::model::ListCryptoKeyVersionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeyVersionsResponse> {
+        self.inner.list_crypto_key_versions(req, options).await
+    }
+
+    async fn list_import_jobs(
+        &self,
+        req: crate::model::ListImportJobsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListImportJobsResponse> {
+        self.inner.list_import_jobs(req, options).await
+    }
+
+    async fn get_key_ring(
+        &self,
+        req: crate::model::GetKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        self.inner.get_key_ring(req, options).await
+    }
+
+    async fn get_crypto_key(
+        &self,
+        req: crate::model::GetCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.get_crypto_key(req, options).await
+    }
+
+    async fn get_crypto_key_version(
+        &self,
+        req: crate::model::GetCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        if let Some(cached) = self.crypto_key_versions.get(&req.name) {
+            return Ok(cached);
+        }
+        let name = req.name.clone();
+        let version = self.inner.get_crypto_key_version(req, options).await?;
+        self.crypto_key_versions.put(name, version.clone());
+        Ok(version)
+    }
+
+    async fn get_public_key(
+        &self,
+        req: crate::model::GetPublicKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::PublicKey> {
+        if let Some(cached) = self.public_keys.get(&req.name) {
+            return Ok(cached);
+        }
+        let name = req.name.clone();
+        let key = self.inner.get_public_key(req, options).await?;
+        self.public_keys.put(name, key.clone());
+        Ok(key)
+    }
+
+    async fn get_import_job(
+        &self,
+        req: crate::model::GetImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        if let Some(cached) = self.import_jobs.get(&req.name) {
+            return Ok(cached);
+        }
+        let name = req.name.clone();
+        let job = self.inner.get_import_job(req, options).await?;
+        self.import_jobs.put(name, job.clone());
+        Ok(job)
+    }
+
+    async fn create_key_ring(
+        &self,
+        req: crate::model::CreateKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        self.inner.create_key_ring(req, options).await
+    }
+
+    async fn create_crypto_key(
+        &self,
+        req: crate::model::CreateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.create_crypto_key(req, options).await
+    }
+
+    async fn create_crypto_key_version(
+        &self,
+        req: crate::model::CreateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.create_crypto_key_version(req, options).await
+    }
+
+    async fn import_crypto_key_version(
+        &self,
+        req: crate::model::ImportCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.import_crypto_key_version(req, options).await
+    }
+
+    async fn create_import_job(
+        &self,
+        req: crate::model::CreateImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        self.inner.create_import_job(req, options).await
+    }
+
+    async fn update_crypto_key(
+        &self,
+        req: crate::model::UpdateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.update_crypto_key(req, options).await
+    }
+
+    async fn update_crypto_key_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let name = req.crypto_key_version.as_ref().map(|v| v.name.clone());
+        let result = self.inner.update_crypto_key_version(req, options).await;
+        if let Some(name) = name {
+            self.crypto_key_versions.invalidate(&name);
+        }
+        result
+    }
+
+    async fn update_crypto_key_primary_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyPrimaryVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner
+            .update_crypto_key_primary_version(req, options)
+            .await
+    }
+
+    async fn destroy_crypto_key_version(
+        &self,
+        req: crate::model::DestroyCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let name = req.name.clone();
+        let result = self.inner.destroy_crypto_key_version(req, options).await;
+        self.crypto_key_versions.invalidate(&name);
+        result
+    }
+
+    async fn restore_crypto_key_version(
+        &self,
+        req: crate::model::RestoreCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let name = req.name.clone();
+        let result = self.inner.restore_crypto_key_version(req, options).await;
+        self.crypto_key_versions.invalidate(&name);
+        result
+    }
+
+    async fn encrypt(
+        &self,
+        req: crate::model::EncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EncryptResponse> {
+        self.inner.encrypt(req, options).await
+    }
+
+    async fn decrypt(
+        &self,
+        req: crate::model::DecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::DecryptResponse> {
+        self.inner.decrypt(req, options).await
+    }
+
+    async fn raw_encrypt(
+        &self,
+        req: crate::model::RawEncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawEncryptResponse> {
+        self.inner.raw_encrypt(req, options).await
+    }
+
+    async fn raw_decrypt(
+        &self,
+        req: crate::model::RawDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawDecryptResponse> {
+        self.inner.raw_decrypt(req, options).await
+    }
+
+    async fn asymmetric_sign(
+        &self,
+        req: crate::model::AsymmetricSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricSignResponse> {
+        self.inner.asymmetric_sign(req, options).await
+    }
+
+    async fn asymmetric_decrypt(
+        &self,
+        req: crate::model::AsymmetricDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricDecryptResponse> {
+        self.inner.asymmetric_decrypt(req, options).await
+    }
+
+    async fn mac_sign(
+        &self,
+        req: crate::model::MacSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacSignResponse> {
+        self.inner.mac_sign(req, options).await
+    }
+
+    async fn mac_verify(
+        &self,
+        req: crate::model::MacVerifyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacVerifyResponse> {
+        self.inner.mac_verify(req, options).await
+    }
+
+    async fn generate_random_bytes(
+        &self,
+        req: crate::model::GenerateRandomBytesRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::GenerateRandomBytesResponse> {
+        self.inner.generate_random_bytes(req, options).await
+    }
+
+    async fn list_locations(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::ListLocationsResponse> {
+        self.inner.list_locations(req, options).await
+    }
+
+    async fn get_location(
+        &self,
+        req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::Location> {
+        self.inner.get_location(req, options).await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        self.inner.set_iam_policy(req, options).await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        self.inner.get_iam_policy(req, options).await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
+        self.inner.test_iam_permissions(req, options).await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        self.inner.get_operation(req, options).await
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A from-scratch CRC32C (Castagnoli) checksum, used by [crate::integrity]
+//! to implement the KMS payload-integrity protocol.
+//!
+//! This is the reflected variant of the algorithm over the polynomial
+//! `0x1EDC6F41`: the register starts at `0xFFFFFFFF`, each byte is folded in
+//! LSB-first through a 256-entry lookup table, and the final register is
+//! XOR-ed with `0xFFFFFFFF`. KMS reports the checksum in a 64-bit wrapper
+//! field, but only the low 32 bits ever carry a nonzero value.
+
+/// The reflection of the CRC32C polynomial `0x1EDC6F41`.
+const POLY: u32 = 0x82f6_3b78;
+
+const fn table_entry(byte: u8) -> u32 {
+    let mut crc = byte as u32;
+    let mut i = 0;
+    while i < 8 {
+        crc = if crc & 1 == 1 {
+            (crc >> 1) ^ POLY
+        } else {
+            crc >> 1
+        };
+        i += 1;
+    }
+    crc
+}
+
+const fn build_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = table_entry(i as u8);
+        i += 1;
+    }
+    table
+}
+
+const TABLE: [u32; 256] = build_table();
+
+/// Computes the CRC32C (Castagnoli) checksum of `data`.
+pub fn checksum(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        let index = ((crc ^ byte as u32) & 0xFF) as usize;
+        crc = TABLE[index] ^ (crc >> 8);
+    }
+    crc ^ 0xFFFF_FFFF
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_known_vectors() {
+        // https://www.rfc-editor.org/rfc/rfc3720#appendix-B.4
+        assert_eq!(checksum(&[]), 0x0000_0000);
+        assert_eq!(checksum(&[0u8; 32]), 0x8a91_36aa);
+        assert_eq!(checksum(&[0xffu8; 32]), 0x62a8_ab43);
+        assert_eq!(
+            checksum(b"123456789"),
+            0xe306_9283,
+            "the standard CRC-32C check value for the ASCII string \"123456789\""
+        );
+    }
+
+    #[test]
+    fn is_stable_across_calls() {
+        let data = b"projects/p/locations/l/keyRings/r/cryptoKeys/k";
+        assert_eq!(checksum(data), checksum(data));
+    }
+}
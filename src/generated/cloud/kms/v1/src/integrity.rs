@@ -0,0 +1,466 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [crate::traits::KeyManagementService] decorator that automates the KMS
+//! payload-integrity protocol.
+//!
+//! KMS supports end-to-end integrity checking via CRC32C checksums: callers
+//! send a checksum of the data they transmit, and the server echoes back
+//! whether it could verify that checksum plus a checksum of the data it
+//! returns. Nothing about the protocol is enforced by the transport, so a
+//! caller (or a compromised proxy in between) can silently corrupt a
+//! payload unless someone actually compares the checksums. [IntegrityVerified]
+//! does that automatically: it fills in the outgoing `*_crc32c` fields
+//! before every call, and rejects the response unless the server's
+//! `verified_*_crc32c` flags are all `true` and the response's own
+//! checksums match the bytes it actually sent back.
+
+use crate::crc32c::checksum;
+use crate::Result;
+
+/// The payload-integrity protocol was violated by the server or a
+/// misbehaving transport in between.
+#[derive(Debug)]
+pub enum IntegrityError {
+    /// The server reported that it could not verify a checksum this
+    /// decorator sent on the request.
+    NotVerified {
+        /// The response flag that reported the failure, e.g. `"verified_plaintext_crc32c"`.
+        field: &'static str,
+    },
+    /// The checksum the server returned for a response field does not match
+    /// the checksum this decorator computed over the bytes it actually
+    /// received.
+    ChecksumMismatch {
+        /// The response field whose checksum did not match, e.g. `"ciphertext_crc32c"`.
+        field: &'static str,
+        /// The checksum reported by the server.
+        reported: u32,
+        /// The checksum this decorator computed locally.
+        computed: u32,
+    },
+}
+
+impl std::fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotVerified { field } => {
+                write!(f, "server did not verify {field}")
+            }
+            Self::ChecksumMismatch {
+                field,
+                reported,
+                computed,
+            } => write!(
+                f,
+                "{field} mismatch: server reported {reported:#010x}, computed {computed:#010x} locally"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IntegrityError {}
+
+fn crc32c_of(data: &[u8]) -> i64 {
+    checksum(data) as i64
+}
+
+fn require_verified(verified: bool, field: &'static str) -> Result<()> {
+    if verified {
+        Ok(())
+    } else {
+        Err(gax::error::Error::other(IntegrityError::NotVerified {
+            field,
+        }))
+    }
+}
+
+fn require_checksum_match(reported: Option<i64>, data: &[u8], field: &'static str) -> Result<()> {
+    let computed = checksum(data);
+    let reported = reported.unwrap_or(0) as u32;
+    if reported == computed {
+        Ok(())
+    } else {
+        Err(gax::error::Error::other(IntegrityError::ChecksumMismatch {
+            field,
+            reported,
+            computed,
+        }))
+    }
+}
+
+/// Implements a [KeyManagementService](crate::traits::KeyManagementService)
+/// decorator that automates the CRC32C payload-integrity protocol.
+///
+/// See the [module][self] documentation for the protocol this enforces.
+#[derive(Clone, Debug)]
+pub struct IntegrityVerified<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+}
+
+impl<T> IntegrityVerified<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> crate::traits::KeyManagementService for IntegrityVerified<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    async fn list_key_rings(
+        &self,
+        req: crate::model::ListKeyRingsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListKeyRingsResponse> {
+        self.inner.list_key_rings(req, options).await
+    }
+
+    async fn list_crypto_keys(
+        &self,
+        req: crate::model::ListCryptoKeysRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeysResponse> {
+        self.inner.list_crypto_keys(req, options).await
+    }
+
+    async fn list_crypto_key_versions(
+        &self,
+        req: crate::model::ListCryptoKeyVersionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeyVersionsResponse> {
+        self.inner.list_crypto_key_versions(req, options).await
+    }
+
+    async fn list_import_jobs(
+        &self,
+        req: crate::model::ListImportJobsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListImportJobsResponse> {
+        self.inner.list_import_jobs(req, options).await
+    }
+
+    async fn get_key_ring(
+        &self,
+        req: crate::model::GetKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        self.inner.get_key_ring(req, options).await
+    }
+
+    async fn get_crypto_key(
+        &self,
+        req: crate::model::GetCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.get_crypto_key(req, options).await
+    }
+
+    async fn get_crypto_key_version(
+        &self,
+        req: crate::model::GetCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.get_crypto_key_version(req, options).await
+    }
+
+    async fn get_public_key(
+        &self,
+        req: crate::model::GetPublicKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::PublicKey> {
+        self.inner.get_public_key(req, options).await
+    }
+
+    async fn get_import_job(
+        &self,
+        req: crate::model::GetImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        self.inner.get_import_job(req, options).await
+    }
+
+    async fn create_key_ring(
+        &self,
+        req: crate::model::CreateKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        self.inner.create_key_ring(req, options).await
+    }
+
+    async fn create_crypto_key(
+        &self,
+        req: crate::model::CreateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.create_crypto_key(req, options).await
+    }
+
+    async fn create_crypto_key_version(
+        &self,
+        req: crate::model::CreateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.create_crypto_key_version(req, options).await
+    }
+
+    async fn import_crypto_key_version(
+        &self,
+        req: crate::model::ImportCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.import_crypto_key_version(req, options).await
+    }
+
+    async fn create_import_job(
+        &self,
+        req: crate::model::CreateImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        self.inner.create_import_job(req, options).await
+    }
+
+    async fn update_crypto_key(
+        &self,
+        req: crate::model::UpdateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.update_crypto_key(req, options).await
+    }
+
+    async fn update_crypto_key_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.update_crypto_key_version(req, options).await
+    }
+
+    async fn update_crypto_key_primary_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyPrimaryVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner
+            .update_crypto_key_primary_version(req, options)
+            .await
+    }
+
+    async fn destroy_crypto_key_version(
+        &self,
+        req: crate::model::DestroyCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.destroy_crypto_key_version(req, options).await
+    }
+
+    async fn restore_crypto_key_version(
+        &self,
+        req: crate::model::RestoreCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.restore_crypto_key_version(req, options).await
+    }
+
+    async fn encrypt(
+        &self,
+        mut req: crate::model::EncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EncryptResponse> {
+        req.plaintext_crc32c = Some(crc32c_of(&req.plaintext));
+        let resp = self.inner.encrypt(req, options).await?;
+        require_verified(resp.verified_plaintext_crc32c, "verified_plaintext_crc32c")?;
+        require_checksum_match(resp.ciphertext_crc32c, &resp.ciphertext, "ciphertext_crc32c")?;
+        Ok(resp)
+    }
+
+    async fn decrypt(
+        &self,
+        mut req: crate::model::DecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::DecryptResponse> {
+        req.ciphertext_crc32c = Some(crc32c_of(&req.ciphertext));
+        let resp = self.inner.decrypt(req, options).await?;
+        require_checksum_match(resp.plaintext_crc32c, &resp.plaintext, "plaintext_crc32c")?;
+        Ok(resp)
+    }
+
+    async fn raw_encrypt(
+        &self,
+        mut req: crate::model::RawEncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawEncryptResponse> {
+        req.plaintext_crc32c = Some(crc32c_of(&req.plaintext));
+        let resp = self.inner.raw_encrypt(req, options).await?;
+        require_verified(resp.verified_plaintext_crc32c, "verified_plaintext_crc32c")?;
+        require_checksum_match(resp.ciphertext_crc32c, &resp.ciphertext, "ciphertext_crc32c")?;
+        Ok(resp)
+    }
+
+    async fn raw_decrypt(
+        &self,
+        mut req: crate::model::RawDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawDecryptResponse> {
+        req.ciphertext_crc32c = Some(crc32c_of(&req.ciphertext));
+        let resp = self.inner.raw_decrypt(req, options).await?;
+        require_verified(resp.verified_ciphertext_crc32c, "verified_ciphertext_crc32c")?;
+        require_checksum_match(resp.plaintext_crc32c, &resp.plaintext, "plaintext_crc32c")?;
+        Ok(resp)
+    }
+
+    async fn asymmetric_sign(
+        &self,
+        req: crate::model::AsymmetricSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricSignResponse> {
+        self.inner.asymmetric_sign(req, options).await
+    }
+
+    async fn asymmetric_decrypt(
+        &self,
+        mut req: crate::model::AsymmetricDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricDecryptResponse> {
+        req.ciphertext_crc32c = Some(crc32c_of(&req.ciphertext));
+        let resp = self.inner.asymmetric_decrypt(req, options).await?;
+        require_verified(resp.verified_ciphertext_crc32c, "verified_ciphertext_crc32c")?;
+        require_checksum_match(resp.plaintext_crc32c, &resp.plaintext, "plaintext_crc32c")?;
+        Ok(resp)
+    }
+
+    async fn mac_sign(
+        &self,
+        mut req: crate::model::MacSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacSignResponse> {
+        req.data_crc32c = Some(crc32c_of(&req.data));
+        let resp = self.inner.mac_sign(req, options).await?;
+        require_verified(resp.verified_data_crc32c, "verified_data_crc32c")?;
+        require_checksum_match(resp.mac_crc32c, &resp.mac, "mac_crc32c")?;
+        Ok(resp)
+    }
+
+    async fn mac_verify(
+        &self,
+        mut req: crate::model::MacVerifyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacVerifyResponse> {
+        req.data_crc32c = Some(crc32c_of(&req.data));
+        req.mac_crc32c = Some(crc32c_of(&req.mac));
+        let resp = self.inner.mac_verify(req, options).await?;
+        require_verified(resp.verified_data_crc32c, "verified_data_crc32c")?;
+        require_verified(resp.verified_mac_crc32c, "verified_mac_crc32c")?;
+        Ok(resp)
+    }
+
+    async fn generate_random_bytes(
+        &self,
+        req: crate::model::GenerateRandomBytesRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::GenerateRandomBytesResponse> {
+        self.inner.generate_random_bytes(req, options).await
+    }
+
+    async fn list_locations(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::ListLocationsResponse> {
+        self.inner.list_locations(req, options).await
+    }
+
+    async fn get_location(
+        &self,
+        req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::Location> {
+        self.inner.get_location(req, options).await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        self.inner.set_iam_policy(req, options).await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        self.inner.get_iam_policy(req, options).await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
+        self.inner.test_iam_permissions(req, options).await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        self.inner.get_operation(req, options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn require_verified_accepts_true() {
+        assert!(require_verified(true, "verified_data_crc32c").is_ok());
+    }
+
+    #[test]
+    fn require_verified_rejects_false() {
+        let err = require_verified(false, "verified_data_crc32c").unwrap_err();
+        assert!(err.to_string().contains("verified_data_crc32c"));
+    }
+
+    #[test]
+    fn require_checksum_match_accepts_matching_checksum() {
+        let data = b"some plaintext";
+        let reported = crc32c_of(data);
+        assert!(require_checksum_match(Some(reported), data, "plaintext_crc32c").is_ok());
+    }
+
+    #[test]
+    fn require_checksum_match_rejects_mismatch() {
+        let data = b"some plaintext";
+        let wrong = crc32c_of(data) ^ 1;
+        let err = require_checksum_match(Some(wrong), data, "plaintext_crc32c").unwrap_err();
+        assert!(err.to_string().contains("plaintext_crc32c"));
+    }
+
+    #[test]
+    fn require_checksum_match_treats_missing_checksum_as_zero() {
+        let err = require_checksum_match(None, b"non-empty", "plaintext_crc32c").unwrap_err();
+        assert!(err.to_string().contains("plaintext_crc32c"));
+    }
+}
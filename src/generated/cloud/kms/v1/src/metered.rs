@@ -0,0 +1,1229 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A decorator family that records OpenTelemetry-style metrics, independent
+//! of whether the [tracing][crate::tracing] decorators are also in use.
+//!
+//! The generated [tracing][crate::tracing] decorators only produce `tracing`
+//! spans: there is no request-count, error-rate, or latency instrumentation
+//! an operator could use to build an SLO dashboard (e.g. p99 latency of
+//! `decrypt`) without also turning on verbose tracing. [Autokey],
+//! [AutokeyAdmin], [EkmService], and [KeyManagementService] fill that gap:
+//! one decorator per trait, each wrapping the inner trait object the same
+//! way the tracing decorators do, recording a request counter, an error
+//! counter split by status code, and a latency histogram per RPC method via
+//! an injected [MetricsRecorder]. Every measurement is tagged with the
+//! method name and, where the request carries one, the resource's
+//! location.
+//!
+//! Because these decorators wrap the same inner trait object as the tracing
+//! decorators, the two compose freely in either order.
+
+use crate::Result;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Records the metrics [Autokey], [AutokeyAdmin], [EkmService], and
+/// [KeyManagementService] collect.
+///
+/// Implement this directly for a non-OpenTelemetry metrics backend; most
+/// callers can instead use [OpenTelemetryRecorder].
+pub trait MetricsRecorder: Send + Sync {
+    /// Called once per call, immediately before invoking the inner stub.
+    fn record_request(&self, method: &'static str, location: &str);
+    /// Called once per call that returned an error, after `record_latency`.
+    fn record_error(&self, method: &'static str, location: &str, status: &str);
+    /// Called once per call, whether it succeeded or failed.
+    fn record_latency(&self, method: &'static str, location: &str, latency: Duration);
+}
+
+/// A [MetricsRecorder] backed by an [opentelemetry::metrics::Meter].
+pub struct OpenTelemetryRecorder {
+    requests: opentelemetry::metrics::Counter<u64>,
+    errors: opentelemetry::metrics::Counter<u64>,
+    latency: opentelemetry::metrics::Histogram<f64>,
+}
+
+impl OpenTelemetryRecorder {
+    pub fn new(meter: &opentelemetry::metrics::Meter) -> Self {
+        Self {
+            requests: meter.u64_counter("kms.client.request_count").build(),
+            errors: meter.u64_counter("kms.client.error_count").build(),
+            latency: meter
+                .f64_histogram("kms.client.duration")
+                .with_unit("s")
+                .build(),
+        }
+    }
+}
+
+impl MetricsRecorder for OpenTelemetryRecorder {
+    fn record_request(&self, method: &'static str, location: &str) {
+        self.requests.add(
+            1,
+            &[
+                opentelemetry::KeyValue::new("rpc.method", method),
+                opentelemetry::KeyValue::new("location", location.to_string()),
+            ],
+        );
+    }
+
+    fn record_error(&self, method: &'static str, location: &str, status: &str) {
+        self.errors.add(
+            1,
+            &[
+                opentelemetry::KeyValue::new("rpc.method", method),
+                opentelemetry::KeyValue::new("location", location.to_string()),
+                opentelemetry::KeyValue::new("status", status.to_string()),
+            ],
+        );
+    }
+
+    fn record_latency(&self, method: &'static str, location: &str, latency: Duration) {
+        self.latency.record(
+            latency.as_secs_f64(),
+            &[
+                opentelemetry::KeyValue::new("rpc.method", method),
+                opentelemetry::KeyValue::new("location", location.to_string()),
+            ],
+        );
+    }
+}
+
+/// Extracts the `{location}` segment from a resource name like
+/// `projects/{project}/locations/{location}/keyRings/{key_ring}`, or `""` if
+/// the resource has no location (e.g. an Autokey config, which is
+/// folder-scoped).
+fn location_of(name: &str) -> &str {
+    let mut segments = name.split('/');
+    while let Some(segment) = segments.next() {
+        if segment == "locations" {
+            return segments.next().unwrap_or("");
+        }
+    }
+    ""
+}
+
+fn status_label(error: &gax::error::Error) -> String {
+    error
+        .http_status_code()
+        .map(|code| code.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+async fn measured<V>(
+    recorder: &Arc<dyn MetricsRecorder>,
+    method: &'static str,
+    location: &str,
+    fut: impl std::future::Future<Output = Result<V>>,
+) -> Result<V> {
+    recorder.record_request(method, location);
+    let start = Instant::now();
+    let result = fut.await;
+    recorder.record_latency(method, location, start.elapsed());
+    if let Err(error) = &result {
+        recorder.record_error(method, location, &status_label(error));
+    }
+    result
+}
+
+/// Implements an [Autokey](crate::traits::Autokey) decorator for metrics.
+#[derive(Clone, Debug)]
+pub struct Autokey<T>
+where
+    T: crate::traits::Autokey + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    recorder: Arc<dyn MetricsRecorder>,
+}
+
+impl<T> Autokey<T>
+where
+    T: crate::traits::Autokey + std::fmt::Debug + Send + Sync,
+{
+    pub fn new(inner: T, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+impl<T> crate::traits::Autokey for Autokey<T>
+where
+    T: crate::traits::Autokey + std::fmt::Debug + Send + Sync,
+{
+    async fn create_key_handle(
+        &self,
+        req: crate::model::CreateKeyHandleRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "Autokey.create_key_handle",
+            &location,
+            self.inner.create_key_handle(req, options),
+        )
+        .await
+    }
+
+    async fn get_key_handle(
+        &self,
+        req: crate::model::GetKeyHandleRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyHandle> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "Autokey.get_key_handle",
+            &location,
+            self.inner.get_key_handle(req, options),
+        )
+        .await
+    }
+
+    async fn list_key_handles(
+        &self,
+        req: crate::model::ListKeyHandlesRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListKeyHandlesResponse> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "Autokey.list_key_handles",
+            &location,
+            self.inner.list_key_handles(req, options),
+        )
+        .await
+    }
+
+    async fn list_locations(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::ListLocationsResponse> {
+        measured(
+            &self.recorder,
+            "Autokey.list_locations",
+            "",
+            self.inner.list_locations(req, options),
+        )
+        .await
+    }
+
+    async fn get_location(
+        &self,
+        req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::Location> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "Autokey.get_location",
+            &location,
+            self.inner.get_location(req, options),
+        )
+        .await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "Autokey.set_iam_policy",
+            &location,
+            self.inner.set_iam_policy(req, options),
+        )
+        .await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "Autokey.get_iam_policy",
+            &location,
+            self.inner.get_iam_policy(req, options),
+        )
+        .await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "Autokey.test_iam_permissions",
+            &location,
+            self.inner.test_iam_permissions(req, options),
+        )
+        .await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "Autokey.get_operation",
+            &location,
+            self.inner.get_operation(req, options),
+        )
+        .await
+    }
+}
+
+/// Implements an [AutokeyAdmin](crate::traits::AutokeyAdmin) decorator for
+/// metrics.
+#[derive(Clone, Debug)]
+pub struct AutokeyAdmin<T>
+where
+    T: crate::traits::AutokeyAdmin + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    recorder: Arc<dyn MetricsRecorder>,
+}
+
+impl<T> AutokeyAdmin<T>
+where
+    T: crate::traits::AutokeyAdmin + std::fmt::Debug + Send + Sync,
+{
+    pub fn new(inner: T, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+impl<T> crate::traits::AutokeyAdmin for AutokeyAdmin<T>
+where
+    T: crate::traits::AutokeyAdmin + std::fmt::Debug + Send + Sync,
+{
+    async fn update_autokey_config(
+        &self,
+        req: crate::model::UpdateAutokeyConfigRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AutokeyConfig> {
+        let location = req
+            .autokey_config
+            .as_ref()
+            .map(|c| location_of(&c.name).to_string())
+            .unwrap_or_default();
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.update_autokey_config",
+            &location,
+            self.inner.update_autokey_config(req, options),
+        )
+        .await
+    }
+
+    async fn get_autokey_config(
+        &self,
+        req: crate::model::GetAutokeyConfigRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AutokeyConfig> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.get_autokey_config",
+            &location,
+            self.inner.get_autokey_config(req, options),
+        )
+        .await
+    }
+
+    async fn show_effective_autokey_config(
+        &self,
+        req: crate::model::ShowEffectiveAutokeyConfigRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ShowEffectiveAutokeyConfigResponse> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.show_effective_autokey_config",
+            &location,
+            self.inner.show_effective_autokey_config(req, options),
+        )
+        .await
+    }
+
+    async fn list_locations(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::ListLocationsResponse> {
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.list_locations",
+            "",
+            self.inner.list_locations(req, options),
+        )
+        .await
+    }
+
+    async fn get_location(
+        &self,
+        req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::Location> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.get_location",
+            &location,
+            self.inner.get_location(req, options),
+        )
+        .await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.set_iam_policy",
+            &location,
+            self.inner.set_iam_policy(req, options),
+        )
+        .await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.get_iam_policy",
+            &location,
+            self.inner.get_iam_policy(req, options),
+        )
+        .await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.test_iam_permissions",
+            &location,
+            self.inner.test_iam_permissions(req, options),
+        )
+        .await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "AutokeyAdmin.get_operation",
+            &location,
+            self.inner.get_operation(req, options),
+        )
+        .await
+    }
+}
+
+/// Implements an [EkmService](crate::traits::EkmService) decorator for
+/// metrics.
+#[derive(Clone, Debug)]
+pub struct EkmService<T>
+where
+    T: crate::traits::EkmService + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    recorder: Arc<dyn MetricsRecorder>,
+}
+
+impl<T> EkmService<T>
+where
+    T: crate::traits::EkmService + std::fmt::Debug + Send + Sync,
+{
+    pub fn new(inner: T, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+impl<T> crate::traits::EkmService for EkmService<T>
+where
+    T: crate::traits::EkmService + std::fmt::Debug + Send + Sync,
+{
+    async fn list_ekm_connections(
+        &self,
+        req: crate::model::ListEkmConnectionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListEkmConnectionsResponse> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.list_ekm_connections",
+            &location,
+            self.inner.list_ekm_connections(req, options),
+        )
+        .await
+    }
+
+    async fn get_ekm_connection(
+        &self,
+        req: crate::model::GetEkmConnectionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EkmConnection> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.get_ekm_connection",
+            &location,
+            self.inner.get_ekm_connection(req, options),
+        )
+        .await
+    }
+
+    async fn create_ekm_connection(
+        &self,
+        req: crate::model::CreateEkmConnectionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EkmConnection> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.create_ekm_connection",
+            &location,
+            self.inner.create_ekm_connection(req, options),
+        )
+        .await
+    }
+
+    async fn update_ekm_connection(
+        &self,
+        req: crate::model::UpdateEkmConnectionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EkmConnection> {
+        let location = req
+            .ekm_connection
+            .as_ref()
+            .map(|c| location_of(&c.name).to_string())
+            .unwrap_or_default();
+        measured(
+            &self.recorder,
+            "EkmService.update_ekm_connection",
+            &location,
+            self.inner.update_ekm_connection(req, options),
+        )
+        .await
+    }
+
+    async fn get_ekm_config(
+        &self,
+        req: crate::model::GetEkmConfigRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EkmConfig> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.get_ekm_config",
+            &location,
+            self.inner.get_ekm_config(req, options),
+        )
+        .await
+    }
+
+    async fn update_ekm_config(
+        &self,
+        req: crate::model::UpdateEkmConfigRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EkmConfig> {
+        let location = req
+            .ekm_config
+            .as_ref()
+            .map(|c| location_of(&c.name).to_string())
+            .unwrap_or_default();
+        measured(
+            &self.recorder,
+            "EkmService.update_ekm_config",
+            &location,
+            self.inner.update_ekm_config(req, options),
+        )
+        .await
+    }
+
+    async fn verify_connectivity(
+        &self,
+        req: crate::model::VerifyConnectivityRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::VerifyConnectivityResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.verify_connectivity",
+            &location,
+            self.inner.verify_connectivity(req, options),
+        )
+        .await
+    }
+
+    async fn list_locations(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::ListLocationsResponse> {
+        measured(
+            &self.recorder,
+            "EkmService.list_locations",
+            "",
+            self.inner.list_locations(req, options),
+        )
+        .await
+    }
+
+    async fn get_location(
+        &self,
+        req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::Location> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.get_location",
+            &location,
+            self.inner.get_location(req, options),
+        )
+        .await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.set_iam_policy",
+            &location,
+            self.inner.set_iam_policy(req, options),
+        )
+        .await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.get_iam_policy",
+            &location,
+            self.inner.get_iam_policy(req, options),
+        )
+        .await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.test_iam_permissions",
+            &location,
+            self.inner.test_iam_permissions(req, options),
+        )
+        .await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "EkmService.get_operation",
+            &location,
+            self.inner.get_operation(req, options),
+        )
+        .await
+    }
+}
+
+/// Implements a [KeyManagementService](crate::traits::KeyManagementService)
+/// decorator for metrics.
+#[derive(Clone, Debug)]
+pub struct KeyManagementService<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    recorder: Arc<dyn MetricsRecorder>,
+}
+
+impl<T> KeyManagementService<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    pub fn new(inner: T, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+impl<T> crate::traits::KeyManagementService for KeyManagementService<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    async fn list_key_rings(
+        &self,
+        req: crate::model::ListKeyRingsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListKeyRingsResponse> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.list_key_rings",
+            &location,
+            self.inner.list_key_rings(req, options),
+        )
+        .await
+    }
+
+    async fn list_crypto_keys(
+        &self,
+        req: crate::model::ListCryptoKeysRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeysResponse> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.list_crypto_keys",
+            &location,
+            self.inner.list_crypto_keys(req, options),
+        )
+        .await
+    }
+
+    async fn list_crypto_key_versions(
+        &self,
+        req: crate::model::ListCryptoKeyVersionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeyVersionsResponse> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.list_crypto_key_versions",
+            &location,
+            self.inner.list_crypto_key_versions(req, options),
+        )
+        .await
+    }
+
+    async fn list_import_jobs(
+        &self,
+        req: crate::model::ListImportJobsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListImportJobsResponse> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.list_import_jobs",
+            &location,
+            self.inner.list_import_jobs(req, options),
+        )
+        .await
+    }
+
+    async fn get_key_ring(
+        &self,
+        req: crate::model::GetKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.get_key_ring",
+            &location,
+            self.inner.get_key_ring(req, options),
+        )
+        .await
+    }
+
+    async fn get_crypto_key(
+        &self,
+        req: crate::model::GetCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.get_crypto_key",
+            &location,
+            self.inner.get_crypto_key(req, options),
+        )
+        .await
+    }
+
+    async fn get_crypto_key_version(
+        &self,
+        req: crate::model::GetCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.get_crypto_key_version",
+            &location,
+            self.inner.get_crypto_key_version(req, options),
+        )
+        .await
+    }
+
+    async fn get_public_key(
+        &self,
+        req: crate::model::GetPublicKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::PublicKey> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.get_public_key",
+            &location,
+            self.inner.get_public_key(req, options),
+        )
+        .await
+    }
+
+    async fn get_import_job(
+        &self,
+        req: crate::model::GetImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.get_import_job",
+            &location,
+            self.inner.get_import_job(req, options),
+        )
+        .await
+    }
+
+    async fn create_key_ring(
+        &self,
+        req: crate::model::CreateKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.create_key_ring",
+            &location,
+            self.inner.create_key_ring(req, options),
+        )
+        .await
+    }
+
+    async fn create_crypto_key(
+        &self,
+        req: crate::model::CreateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.create_crypto_key",
+            &location,
+            self.inner.create_crypto_key(req, options),
+        )
+        .await
+    }
+
+    async fn create_crypto_key_version(
+        &self,
+        req: crate::model::CreateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.create_crypto_key_version",
+            &location,
+            self.inner.create_crypto_key_version(req, options),
+        )
+        .await
+    }
+
+    async fn import_crypto_key_version(
+        &self,
+        req: crate::model::ImportCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.import_crypto_key_version",
+            &location,
+            self.inner.import_crypto_key_version(req, options),
+        )
+        .await
+    }
+
+    async fn create_import_job(
+        &self,
+        req: crate::model::CreateImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        let location = location_of(&req.parent).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.create_import_job",
+            &location,
+            self.inner.create_import_job(req, options),
+        )
+        .await
+    }
+
+    async fn update_crypto_key(
+        &self,
+        req: crate::model::UpdateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        let location = req
+            .crypto_key
+            .as_ref()
+            .map(|k| location_of(&k.name).to_string())
+            .unwrap_or_default();
+        measured(
+            &self.recorder,
+            "KeyManagementService.update_crypto_key",
+            &location,
+            self.inner.update_crypto_key(req, options),
+        )
+        .await
+    }
+
+    async fn update_crypto_key_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let location = req
+            .crypto_key_version
+            .as_ref()
+            .map(|v| location_of(&v.name).to_string())
+            .unwrap_or_default();
+        measured(
+            &self.recorder,
+            "KeyManagementService.update_crypto_key_version",
+            &location,
+            self.inner.update_crypto_key_version(req, options),
+        )
+        .await
+    }
+
+    async fn update_crypto_key_primary_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyPrimaryVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.update_crypto_key_primary_version",
+            &location,
+            self.inner.update_crypto_key_primary_version(req, options),
+        )
+        .await
+    }
+
+    async fn destroy_crypto_key_version(
+        &self,
+        req: crate::model::DestroyCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.destroy_crypto_key_version",
+            &location,
+            self.inner.destroy_crypto_key_version(req, options),
+        )
+        .await
+    }
+
+    async fn restore_crypto_key_version(
+        &self,
+        req: crate::model::RestoreCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.restore_crypto_key_version",
+            &location,
+            self.inner.restore_crypto_key_version(req, options),
+        )
+        .await
+    }
+
+    async fn encrypt(
+        &self,
+        req: crate::model::EncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EncryptResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.encrypt",
+            &location,
+            self.inner.encrypt(req, options),
+        )
+        .await
+    }
+
+    async fn decrypt(
+        &self,
+        req: crate::model::DecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::DecryptResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.decrypt",
+            &location,
+            self.inner.decrypt(req, options),
+        )
+        .await
+    }
+
+    async fn raw_encrypt(
+        &self,
+        req: crate::model::RawEncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawEncryptResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.raw_encrypt",
+            &location,
+            self.inner.raw_encrypt(req, options),
+        )
+        .await
+    }
+
+    async fn raw_decrypt(
+        &self,
+        req: crate::model::RawDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawDecryptResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.raw_decrypt",
+            &location,
+            self.inner.raw_decrypt(req, options),
+        )
+        .await
+    }
+
+    async fn asymmetric_sign(
+        &self,
+        req: crate::model::AsymmetricSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricSignResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.asymmetric_sign",
+            &location,
+            self.inner.asymmetric_sign(req, options),
+        )
+        .await
+    }
+
+    async fn asymmetric_decrypt(
+        &self,
+        req: crate::model::AsymmetricDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricDecryptResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.asymmetric_decrypt",
+            &location,
+            self.inner.asymmetric_decrypt(req, options),
+        )
+        .await
+    }
+
+    async fn mac_sign(
+        &self,
+        req: crate::model::MacSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacSignResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.mac_sign",
+            &location,
+            self.inner.mac_sign(req, options),
+        )
+        .await
+    }
+
+    async fn mac_verify(
+        &self,
+        req: crate::model::MacVerifyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacVerifyResponse> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.mac_verify",
+            &location,
+            self.inner.mac_verify(req, options),
+        )
+        .await
+    }
+
+    async fn generate_random_bytes(
+        &self,
+        req: crate::model::GenerateRandomBytesRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::GenerateRandomBytesResponse> {
+        let location = location_of(&req.location).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.generate_random_bytes",
+            &location,
+            self.inner.generate_random_bytes(req, options),
+        )
+        .await
+    }
+
+    async fn list_locations(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::ListLocationsResponse> {
+        measured(
+            &self.recorder,
+            "KeyManagementService.list_locations",
+            "",
+            self.inner.list_locations(req, options),
+        )
+        .await
+    }
+
+    async fn get_location(
+        &self,
+        req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::Location> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.get_location",
+            &location,
+            self.inner.get_location(req, options),
+        )
+        .await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.set_iam_policy",
+            &location,
+            self.inner.set_iam_policy(req, options),
+        )
+        .await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.get_iam_policy",
+            &location,
+            self.inner.get_iam_policy(req, options),
+        )
+        .await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
+        let location = location_of(&req.resource).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.test_iam_permissions",
+            &location,
+            self.inner.test_iam_permissions(req, options),
+        )
+        .await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        let location = location_of(&req.name).to_string();
+        measured(
+            &self.recorder,
+            "KeyManagementService.get_operation",
+            &location,
+            self.inner.get_operation(req, options),
+        )
+        .await
+    }
+}
@@ -0,0 +1,581 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [crate::traits::KeyManagementService] decorator that redacts secret key
+//! material before logging it.
+//!
+//! The generated [crate::tracing::KeyManagementService] decorator annotates
+//! every method with `#[tracing::instrument(ret)]`, which logs the full
+//! request and response via their `Debug` implementations. For the methods
+//! that carry key material -- [encrypt][crate::traits::KeyManagementService::encrypt],
+//! [decrypt][crate::traits::KeyManagementService::decrypt],
+//! [raw_encrypt][crate::traits::KeyManagementService::raw_encrypt],
+//! [raw_decrypt][crate::traits::KeyManagementService::raw_decrypt],
+//! [mac_sign][crate::traits::KeyManagementService::mac_sign],
+//! [mac_verify][crate::traits::KeyManagementService::mac_verify],
+//! [generate_random_bytes][crate::traits::KeyManagementService::generate_random_bytes],
+//! and [asymmetric_decrypt][crate::traits::KeyManagementService::asymmetric_decrypt]
+//! -- that would log plaintext, ciphertext, raw MAC values, and freshly
+//! generated random bytes to whatever subscriber the application has
+//! installed.
+//!
+//! [Redacting] is a drop-in replacement for [crate::tracing::KeyManagementService]
+//! that instruments those eight methods by hand, logging a stable
+//! `<redacted:N bytes>` placeholder for secret fields instead of their
+//! contents, while still logging the fields that are safe and useful for
+//! debugging (resource `name`s, `protection_level`, and the
+//! `verified_*_crc32c` flags). The remaining methods carry no secret material, so
+//! they are instrumented the same way the generated decorator does.
+
+use crate::Result;
+use tracing::Instrument;
+
+/// Controls which fields [Redacting] masks before logging.
+///
+/// The default redacts every field that can carry secret material, which is
+/// the safe choice for production. Construct a policy with every field set
+/// to `false` to opt into full logging in a local development environment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RedactionPolicy {
+    /// Redact `plaintext` fields, e.g. on
+    /// [EncryptRequest][crate::model::EncryptRequest] and
+    /// [DecryptResponse][crate::model::DecryptResponse].
+    pub redact_plaintext: bool,
+    /// Redact `ciphertext` fields, e.g. on
+    /// [EncryptResponse][crate::model::EncryptResponse] and
+    /// [DecryptRequest][crate::model::DecryptRequest].
+    pub redact_ciphertext: bool,
+    /// Redact the `mac` field produced by
+    /// [mac_sign][crate::traits::KeyManagementService::mac_sign] and
+    /// consumed by [mac_verify][crate::traits::KeyManagementService::mac_verify].
+    pub redact_mac: bool,
+    /// Redact the `data` field returned by
+    /// [generate_random_bytes][crate::traits::KeyManagementService::generate_random_bytes].
+    pub redact_random_bytes: bool,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self {
+            redact_plaintext: true,
+            redact_ciphertext: true,
+            redact_mac: true,
+            redact_random_bytes: true,
+        }
+    }
+}
+
+/// Renders `bytes` as a stable placeholder, or as its normal `Debug` form if
+/// `redact` is `false`.
+fn show(redact: bool, bytes: &[u8]) -> String {
+    if redact {
+        format!("<redacted:{} bytes>", bytes.len())
+    } else {
+        format!("{bytes:?}")
+    }
+}
+
+/// Implements a [KeyManagementService](crate::traits::KeyManagementService)
+/// decorator that redacts secret key material before logging it.
+///
+/// See the [module][self] documentation for why this exists instead of the
+/// generated [crate::tracing::KeyManagementService] decorator.
+#[derive(Clone, Debug)]
+pub struct Redacting<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    policy: RedactionPolicy,
+}
+
+impl<T> Redacting<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    /// Creates a new decorator with the default (fully redacted) policy.
+    pub fn new(inner: T) -> Self {
+        Self::new_with_policy(inner, RedactionPolicy::default())
+    }
+
+    /// Creates a new decorator with an explicit [RedactionPolicy].
+    ///
+    /// Callers that want to opt into full logging in development, but keep
+    /// the safe default in production, typically derive this policy from
+    /// [gax::options::RequestOptions] when building the client rather than
+    /// hard-coding it here.
+    pub fn new_with_policy(inner: T, policy: RedactionPolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+impl<T> crate::traits::KeyManagementService for Redacting<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    #[tracing::instrument(ret)]
+    async fn list_key_rings(
+        &self,
+        req: crate::model::ListKeyRingsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListKeyRingsResponse> {
+        self.inner.list_key_rings(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn list_crypto_keys(
+        &self,
+        req: crate::model::ListCryptoKeysRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeysResponse> {
+        self.inner.list_crypto_keys(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn list_crypto_key_versions(
+        &self,
+        req: crate::model::ListCryptoKeyVersionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeyVersionsResponse> {
+        self.inner.list_crypto_key_versions(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn list_import_jobs(
+        &self,
+        req: crate::model::ListImportJobsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListImportJobsResponse> {
+        self.inner.list_import_jobs(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_key_ring(
+        &self,
+        req: crate::model::GetKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        self.inner.get_key_ring(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_crypto_key(
+        &self,
+        req: crate::model::GetCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.get_crypto_key(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_crypto_key_version(
+        &self,
+        req: crate::model::GetCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.get_crypto_key_version(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_public_key(
+        &self,
+        req: crate::model::GetPublicKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::PublicKey> {
+        self.inner.get_public_key(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_import_job(
+        &self,
+        req: crate::model::GetImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        self.inner.get_import_job(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn create_key_ring(
+        &self,
+        req: crate::model::CreateKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        self.inner.create_key_ring(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn create_crypto_key(
+        &self,
+        req: crate::model::CreateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.create_crypto_key(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn create_crypto_key_version(
+        &self,
+        req: crate::model::CreateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.create_crypto_key_version(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn import_crypto_key_version(
+        &self,
+        req: crate::model::ImportCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.import_crypto_key_version(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn create_import_job(
+        &self,
+        req: crate::model::CreateImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        self.inner.create_import_job(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn update_crypto_key(
+        &self,
+        req: crate::model::UpdateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.update_crypto_key(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn update_crypto_key_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.update_crypto_key_version(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn update_crypto_key_primary_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyPrimaryVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner
+            .update_crypto_key_primary_version(req, options)
+            .await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn destroy_crypto_key_version(
+        &self,
+        req: crate::model::DestroyCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.destroy_crypto_key_version(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn restore_crypto_key_version(
+        &self,
+        req: crate::model::RestoreCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.restore_crypto_key_version(req, options).await
+    }
+
+    async fn encrypt(
+        &self,
+        req: crate::model::EncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EncryptResponse> {
+        let span = tracing::info_span!(
+            "KeyManagementService::encrypt",
+            name = %req.name,
+            plaintext = %show(self.policy.redact_plaintext, &req.plaintext),
+        );
+        let policy = self.policy;
+        async move {
+            let result = self.inner.encrypt(req, options).await;
+            match &result {
+                Ok(resp) => tracing::info!(
+                    name = %resp.name,
+                    ciphertext = %show(policy.redact_ciphertext, &resp.ciphertext),
+                    verified_plaintext_crc32c = resp.verified_plaintext_crc32c,
+                    protection_level = ?resp.protection_level,
+                    "return"
+                ),
+                Err(error) => tracing::info!(%error, "return"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn decrypt(
+        &self,
+        req: crate::model::DecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::DecryptResponse> {
+        let span = tracing::info_span!(
+            "KeyManagementService::decrypt",
+            name = %req.name,
+            ciphertext = %show(self.policy.redact_ciphertext, &req.ciphertext),
+        );
+        let policy = self.policy;
+        async move {
+            let result = self.inner.decrypt(req, options).await;
+            match &result {
+                Ok(resp) => tracing::info!(
+                    plaintext = %show(policy.redact_plaintext, &resp.plaintext),
+                    used_primary = resp.used_primary,
+                    "return"
+                ),
+                Err(error) => tracing::info!(%error, "return"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn raw_encrypt(
+        &self,
+        req: crate::model::RawEncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawEncryptResponse> {
+        let span = tracing::info_span!(
+            "KeyManagementService::raw_encrypt",
+            name = %req.name,
+            plaintext = %show(self.policy.redact_plaintext, &req.plaintext),
+        );
+        let policy = self.policy;
+        async move {
+            let result = self.inner.raw_encrypt(req, options).await;
+            match &result {
+                Ok(resp) => tracing::info!(
+                    name = %resp.name,
+                    ciphertext = %show(policy.redact_ciphertext, &resp.ciphertext),
+                    verified_plaintext_crc32c = resp.verified_plaintext_crc32c,
+                    protection_level = ?resp.protection_level,
+                    "return"
+                ),
+                Err(error) => tracing::info!(%error, "return"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn raw_decrypt(
+        &self,
+        req: crate::model::RawDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawDecryptResponse> {
+        let span = tracing::info_span!(
+            "KeyManagementService::raw_decrypt",
+            name = %req.name,
+            ciphertext = %show(self.policy.redact_ciphertext, &req.ciphertext),
+        );
+        let policy = self.policy;
+        async move {
+            let result = self.inner.raw_decrypt(req, options).await;
+            match &result {
+                Ok(resp) => tracing::info!(
+                    plaintext = %show(policy.redact_plaintext, &resp.plaintext),
+                    protection_level = ?resp.protection_level,
+                    "return"
+                ),
+                Err(error) => tracing::info!(%error, "return"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn asymmetric_sign(
+        &self,
+        req: crate::model::AsymmetricSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricSignResponse> {
+        // The signature is not secret -- it is meant to be shared with
+        // anyone verifying the signed data -- so the generated decorator's
+        // unredacted logging is fine here.
+        self.inner.asymmetric_sign(req, options).await
+    }
+
+    async fn asymmetric_decrypt(
+        &self,
+        req: crate::model::AsymmetricDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricDecryptResponse> {
+        let span = tracing::info_span!(
+            "KeyManagementService::asymmetric_decrypt",
+            name = %req.name,
+            ciphertext = %show(self.policy.redact_ciphertext, &req.ciphertext),
+        );
+        let policy = self.policy;
+        async move {
+            let result = self.inner.asymmetric_decrypt(req, options).await;
+            match &result {
+                Ok(resp) => tracing::info!(
+                    plaintext = %show(policy.redact_plaintext, &resp.plaintext),
+                    verified_ciphertext_crc32c = resp.verified_ciphertext_crc32c,
+                    "return"
+                ),
+                Err(error) => tracing::info!(%error, "return"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn mac_sign(
+        &self,
+        req: crate::model::MacSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacSignResponse> {
+        let span = tracing::info_span!("KeyManagementService::mac_sign", name = %req.name);
+        let policy = self.policy;
+        async move {
+            let result = self.inner.mac_sign(req, options).await;
+            match &result {
+                Ok(resp) => tracing::info!(
+                    name = %resp.name,
+                    mac = %show(policy.redact_mac, &resp.mac),
+                    verified_data_crc32c = resp.verified_data_crc32c,
+                    "return"
+                ),
+                Err(error) => tracing::info!(%error, "return"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn mac_verify(
+        &self,
+        req: crate::model::MacVerifyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacVerifyResponse> {
+        let span = tracing::info_span!(
+            "KeyManagementService::mac_verify",
+            name = %req.name,
+            mac = %show(self.policy.redact_mac, &req.mac),
+        );
+        async move {
+            let result = self.inner.mac_verify(req, options).await;
+            match &result {
+                Ok(resp) => tracing::info!(
+                    success = resp.success,
+                    verified_data_crc32c = resp.verified_data_crc32c,
+                    verified_mac_crc32c = resp.verified_mac_crc32c,
+                    "return"
+                ),
+                Err(error) => tracing::info!(%error, "return"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn generate_random_bytes(
+        &self,
+        req: crate::model::GenerateRandomBytesRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::GenerateRandomBytesResponse> {
+        let span = tracing::info_span!(
+            "KeyManagementService::generate_random_bytes",
+            location = %req.location,
+            length_bytes = req.length_bytes,
+        );
+        let policy = self.policy;
+        async move {
+            let result = self.inner.generate_random_bytes(req, options).await;
+            match &result {
+                Ok(resp) => tracing::info!(
+                    data = %show(policy.redact_random_bytes, &resp.data),
+                    "return"
+                ),
+                Err(error) => tracing::info!(%error, "return"),
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn list_locations(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::ListLocationsResponse> {
+        self.inner.list_locations(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_location(
+        &self,
+        req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::Location> {
+        self.inner.get_location(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        self.inner.set_iam_policy(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        self.inner.get_iam_policy(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
+        self.inner.test_iam_permissions(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        self.inner.get_operation(req, options).await
+    }
+}
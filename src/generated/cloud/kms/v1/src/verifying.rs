@@ -0,0 +1,720 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [crate::traits::KeyManagementService] decorator that locally verifies
+//! signatures and MACs before trusting them.
+//!
+//! [asymmetric_sign][crate::traits::KeyManagementService::asymmetric_sign]
+//! and [mac_sign][crate::traits::KeyManagementService::mac_sign]/
+//! [mac_verify][crate::traits::KeyManagementService::mac_verify] return a
+//! cryptographic result that most callers want validated before they act on
+//! it, rather than trusting the transport and the server's own bookkeeping
+//! flags unconditionally. [Verifying] does that validation locally:
+//!
+//! * After `asymmetric_sign`, it fetches (and caches, by crypto key version
+//!   name) the matching [PublicKey][crate::model::PublicKey] via
+//!   `get_public_key` and verifies the returned signature against the
+//!   digest that was actually signed, using the key's
+//!   [CryptoKeyVersionAlgorithm][crate::model::CryptoKeyVersionAlgorithm].
+//! * After `mac_verify`, it checks the server's `verified_*_crc32c` flags
+//!   and, if the caller supplied a way to look up the raw MAC key material
+//!   (KMS itself never returns it), independently recomputes the HMAC --
+//!   using the crypto key version's actual MAC algorithm, fetched via
+//!   `get_crypto_key_version` and cached the same way `asymmetric_sign`'s
+//!   public keys are -- and rejects the response unless both that
+//!   recomputation and the server's `success` flag accept the MAC the
+//!   caller asked the server to verify. Without that key material the
+//!   `success` flag is passed through unchecked.
+//!
+//! Either check failing returns an error instead of the response.
+
+use crate::Result;
+use hmac::{Hmac, Mac};
+use p256::ecdsa::signature::hazmat::PrehashVerifier as _;
+use pkcs1::DecodeRsaPublicKey as _;
+use pkcs8::DecodePublicKey as _;
+use rsa::signature::hazmat::PrehashVerifier as RsaPrehashVerifier;
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A locally-performed verification failed, or could not be performed.
+#[derive(Debug)]
+pub enum VerificationError {
+    /// The key's [CryptoKeyVersionAlgorithm][crate::model::CryptoKeyVersionAlgorithm]
+    /// is not one this decorator knows how to verify locally.
+    UnsupportedAlgorithm(crate::model::CryptoKeyVersionAlgorithm),
+    /// The PEM-encoded public key KMS returned could not be parsed.
+    MalformedPublicKey(String),
+    /// The signature does not validate against the digest and public key.
+    SignatureInvalid,
+    /// The independently recomputed MAC does not match the one the caller
+    /// asked the server to verify.
+    MacMismatch,
+    /// The server did not report that it could verify the named field.
+    NotVerified { field: &'static str },
+}
+
+impl std::fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedAlgorithm(algorithm) => {
+                write!(f, "no local verifier for algorithm {algorithm:?}")
+            }
+            Self::MalformedPublicKey(detail) => write!(f, "malformed public key: {detail}"),
+            Self::SignatureInvalid => write!(f, "signature failed local verification"),
+            Self::MacMismatch => write!(f, "recomputed MAC does not match"),
+            Self::NotVerified { field } => write!(f, "server did not verify {field}"),
+        }
+    }
+}
+
+impl std::error::Error for VerificationError {}
+
+fn digest_bytes(digest: &Option<crate::model::Digest>) -> &[u8] {
+    use crate::model::digest::Digest as D;
+    match digest.as_ref().and_then(|d| d.digest.as_ref()) {
+        Some(D::Sha256(bytes)) => bytes,
+        Some(D::Sha384(bytes)) => bytes,
+        Some(D::Sha512(bytes)) => bytes,
+        None => &[],
+    }
+}
+
+/// Verifies `signature` over the already-hashed `digest`, using the scheme
+/// and hash implied by `algorithm` and the key material in `pem`.
+///
+/// KMS signs the digest the caller computed, not a raw message, so
+/// verification always goes through the "prehash" entry points rather than
+/// hashing `digest` a second time.
+fn verify_signature(
+    pem: &str,
+    algorithm: crate::model::CryptoKeyVersionAlgorithm,
+    digest: &[u8],
+    signature: &[u8],
+) -> Result<()> {
+    use crate::model::CryptoKeyVersionAlgorithm as Algorithm;
+    let ok = match algorithm {
+        Algorithm::EcSignP256Sha256 => {
+            let key = p256::ecdsa::VerifyingKey::from_public_key_pem(pem).map_err(|e| {
+                gax::error::Error::other(VerificationError::MalformedPublicKey(e.to_string()))
+            })?;
+            let sig = p256::ecdsa::Signature::from_der(signature)
+                .map_err(|_| gax::error::Error::other(VerificationError::SignatureInvalid))?;
+            key.verify_prehash(digest, &sig).is_ok()
+        }
+        Algorithm::EcSignP384Sha384 => {
+            let key = p384::ecdsa::VerifyingKey::from_public_key_pem(pem).map_err(|e| {
+                gax::error::Error::other(VerificationError::MalformedPublicKey(e.to_string()))
+            })?;
+            let sig = p384::ecdsa::Signature::from_der(signature)
+                .map_err(|_| gax::error::Error::other(VerificationError::SignatureInvalid))?;
+            key.verify_prehash(digest, &sig).is_ok()
+        }
+        Algorithm::RsaSignPss2048Sha256
+        | Algorithm::RsaSignPss3072Sha256
+        | Algorithm::RsaSignPss4096Sha256 => verify_rsa_pss::<Sha256>(pem, digest, signature)?,
+        Algorithm::RsaSignPss4096Sha512 => verify_rsa_pss::<Sha512>(pem, digest, signature)?,
+        Algorithm::RsaSignPkcs12048Sha256
+        | Algorithm::RsaSignPkcs13072Sha256
+        | Algorithm::RsaSignPkcs14096Sha256 => verify_rsa_pkcs1::<Sha256>(pem, digest, signature)?,
+        Algorithm::RsaSignPkcs14096Sha512 => verify_rsa_pkcs1::<Sha512>(pem, digest, signature)?,
+        other => return Err(gax::error::Error::other(VerificationError::UnsupportedAlgorithm(other))),
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(gax::error::Error::other(VerificationError::SignatureInvalid))
+    }
+}
+
+/// Verifies `mac` over `data`, using the key material in `key` and the HMAC
+/// variant implied by `algorithm`.
+///
+/// KMS supports HMAC_SHA1/224/256/384/512 for MAC keys, so this dispatches
+/// on the crypto key version's algorithm the same way [verify_signature]
+/// does for signing keys, rather than assuming SHA256.
+fn verify_hmac(
+    algorithm: crate::model::CryptoKeyVersionAlgorithm,
+    key: &[u8],
+    data: &[u8],
+    mac: &[u8],
+) -> Result<bool> {
+    use crate::model::CryptoKeyVersionAlgorithm as Algorithm;
+    let ok = match algorithm {
+        Algorithm::HmacSha1 => {
+            let mut hmac =
+                Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts a key of any length");
+            hmac.update(data);
+            hmac.verify_slice(mac).is_ok()
+        }
+        Algorithm::HmacSha224 => {
+            let mut hmac =
+                Hmac::<Sha224>::new_from_slice(key).expect("HMAC accepts a key of any length");
+            hmac.update(data);
+            hmac.verify_slice(mac).is_ok()
+        }
+        Algorithm::HmacSha256 => {
+            let mut hmac =
+                Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts a key of any length");
+            hmac.update(data);
+            hmac.verify_slice(mac).is_ok()
+        }
+        Algorithm::HmacSha384 => {
+            let mut hmac =
+                Hmac::<Sha384>::new_from_slice(key).expect("HMAC accepts a key of any length");
+            hmac.update(data);
+            hmac.verify_slice(mac).is_ok()
+        }
+        Algorithm::HmacSha512 => {
+            let mut hmac =
+                Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts a key of any length");
+            hmac.update(data);
+            hmac.verify_slice(mac).is_ok()
+        }
+        other => {
+            return Err(gax::error::Error::other(
+                VerificationError::UnsupportedAlgorithm(other),
+            ))
+        }
+    };
+    Ok(ok)
+}
+
+fn rsa_public_key(pem: &str) -> Result<rsa::RsaPublicKey> {
+    rsa::RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| rsa::RsaPublicKey::from_pkcs1_pem(pem))
+        .map_err(|e| gax::error::Error::other(VerificationError::MalformedPublicKey(e.to_string())))
+}
+
+fn verify_rsa_pss<D>(pem: &str, digest: &[u8], signature: &[u8]) -> Result<bool>
+where
+    D: sha2::Digest,
+{
+    let key = rsa::pss::VerifyingKey::<D>::new(rsa_public_key(pem)?);
+    let signature = rsa::pss::Signature::try_from(signature)
+        .map_err(|_| gax::error::Error::other(VerificationError::SignatureInvalid))?;
+    Ok(key.verify_prehash(digest, &signature).is_ok())
+}
+
+fn verify_rsa_pkcs1<D>(pem: &str, digest: &[u8], signature: &[u8]) -> Result<bool>
+where
+    D: rsa::sha2::Digest,
+{
+    let key = rsa::pkcs1v15::VerifyingKey::<D>::new(rsa_public_key(pem)?);
+    let signature = rsa::pkcs1v15::Signature::try_from(signature)
+        .map_err(|_| gax::error::Error::other(VerificationError::SignatureInvalid))?;
+    Ok(key.verify_prehash(digest, &signature).is_ok())
+}
+
+/// Looks up the raw key material for a MAC key by crypto key version name.
+///
+/// KMS never returns MAC key material -- it is only usable inside the
+/// service -- so this has no real source unless the caller separately holds
+/// the same key (e.g. an imported key they also kept a local copy of) and
+/// chooses to register it here. Without a provider, [Verifying] still
+/// checks the server's `verified_*_crc32c` flags, it just cannot
+/// independently recompute the MAC.
+pub type LocalMacKeyProvider = Arc<dyn Fn(&str) -> Option<Vec<u8>> + Send + Sync>;
+
+/// Implements a [KeyManagementService](crate::traits::KeyManagementService)
+/// decorator that locally verifies signatures and MACs.
+///
+/// See the [module][self] documentation for what is (and, for `mac_verify`,
+/// is not) checked locally.
+#[derive(Clone)]
+pub struct Verifying<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    public_keys: Arc<Mutex<HashMap<String, crate::model::PublicKey>>>,
+    mac_algorithms: Arc<Mutex<HashMap<String, crate::model::CryptoKeyVersionAlgorithm>>>,
+    local_mac_keys: Option<LocalMacKeyProvider>,
+}
+
+impl<T> std::fmt::Debug for Verifying<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Verifying").field("inner", &self.inner).finish()
+    }
+}
+
+impl<T> Verifying<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    /// Creates a new decorator with no local MAC key provider: `mac_verify`
+    /// only cross-checks the server's flags.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            public_keys: Arc::new(Mutex::new(HashMap::new())),
+            mac_algorithms: Arc::new(Mutex::new(HashMap::new())),
+            local_mac_keys: None,
+        }
+    }
+
+    /// Creates a new decorator that additionally recomputes the HMAC for
+    /// `mac_verify` whenever `provider` can resolve the key's raw material.
+    pub fn with_local_mac_key_provider(
+        inner: T,
+        provider: impl Fn(&str) -> Option<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            inner,
+            public_keys: Arc::new(Mutex::new(HashMap::new())),
+            mac_algorithms: Arc::new(Mutex::new(HashMap::new())),
+            local_mac_keys: Some(Arc::new(provider)),
+        }
+    }
+
+    async fn public_key(
+        &self,
+        name: &str,
+        options: &gax::options::RequestOptions,
+    ) -> Result<crate::model::PublicKey> {
+        if let Some(cached) = self.public_keys.lock().unwrap().get(name).cloned() {
+            return Ok(cached);
+        }
+        let key = self
+            .inner
+            .get_public_key(
+                crate::model::GetPublicKeyRequest {
+                    name: name.to_string(),
+                    ..Default::default()
+                },
+                options.clone(),
+            )
+            .await?;
+        self.public_keys
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), key.clone());
+        Ok(key)
+    }
+
+    async fn mac_algorithm(
+        &self,
+        name: &str,
+        options: &gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersionAlgorithm> {
+        if let Some(cached) = self.mac_algorithms.lock().unwrap().get(name).cloned() {
+            return Ok(cached);
+        }
+        let version = self
+            .inner
+            .get_crypto_key_version(
+                crate::model::GetCryptoKeyVersionRequest {
+                    name: name.to_string(),
+                    ..Default::default()
+                },
+                options.clone(),
+            )
+            .await?;
+        self.mac_algorithms
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), version.algorithm);
+        Ok(version.algorithm)
+    }
+}
+
+impl<T> crate::traits::KeyManagementService for Verifying<T>
+where
+    T: crate::traits::KeyManagementService + std::fmt::Debug + Send + Sync,
+{
+    async fn list_key_rings(
+        &self,
+        req: crate::model::ListKeyRingsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListKeyRingsResponse> {
+        self.inner.list_key_rings(req, options).await
+    }
+
+    async fn list_crypto_keys(
+        &self,
+        req: crate::model::ListCryptoKeysRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeysResponse> {
+        self.inner.list_crypto_keys(req, options).await
+    }
+
+    async fn list_crypto_key_versions(
+        &self,
+        req: crate::model::ListCryptoKeyVersionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListCryptoKeyVersionsResponse> {
+        self.inner.list_crypto_key_versions(req, options).await
+    }
+
+    async fn list_import_jobs(
+        &self,
+        req: crate::model::ListImportJobsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ListImportJobsResponse> {
+        self.inner.list_import_jobs(req, options).await
+    }
+
+    async fn get_key_ring(
+        &self,
+        req: crate::model::GetKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        self.inner.get_key_ring(req, options).await
+    }
+
+    async fn get_crypto_key(
+        &self,
+        req: crate::model::GetCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.get_crypto_key(req, options).await
+    }
+
+    async fn get_crypto_key_version(
+        &self,
+        req: crate::model::GetCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.get_crypto_key_version(req, options).await
+    }
+
+    async fn get_public_key(
+        &self,
+        req: crate::model::GetPublicKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::PublicKey> {
+        self.inner.get_public_key(req, options).await
+    }
+
+    async fn get_import_job(
+        &self,
+        req: crate::model::GetImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        self.inner.get_import_job(req, options).await
+    }
+
+    async fn create_key_ring(
+        &self,
+        req: crate::model::CreateKeyRingRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::KeyRing> {
+        self.inner.create_key_ring(req, options).await
+    }
+
+    async fn create_crypto_key(
+        &self,
+        req: crate::model::CreateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.create_crypto_key(req, options).await
+    }
+
+    async fn create_crypto_key_version(
+        &self,
+        req: crate::model::CreateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.create_crypto_key_version(req, options).await
+    }
+
+    async fn import_crypto_key_version(
+        &self,
+        req: crate::model::ImportCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.import_crypto_key_version(req, options).await
+    }
+
+    async fn create_import_job(
+        &self,
+        req: crate::model::CreateImportJobRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::ImportJob> {
+        self.inner.create_import_job(req, options).await
+    }
+
+    async fn update_crypto_key(
+        &self,
+        req: crate::model::UpdateCryptoKeyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner.update_crypto_key(req, options).await
+    }
+
+    async fn update_crypto_key_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.update_crypto_key_version(req, options).await
+    }
+
+    async fn update_crypto_key_primary_version(
+        &self,
+        req: crate::model::UpdateCryptoKeyPrimaryVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKey> {
+        self.inner
+            .update_crypto_key_primary_version(req, options)
+            .await
+    }
+
+    async fn destroy_crypto_key_version(
+        &self,
+        req: crate::model::DestroyCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.destroy_crypto_key_version(req, options).await
+    }
+
+    async fn restore_crypto_key_version(
+        &self,
+        req: crate::model::RestoreCryptoKeyVersionRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::CryptoKeyVersion> {
+        self.inner.restore_crypto_key_version(req, options).await
+    }
+
+    async fn encrypt(
+        &self,
+        req: crate::model::EncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::EncryptResponse> {
+        self.inner.encrypt(req, options).await
+    }
+
+    async fn decrypt(
+        &self,
+        req: crate::model::DecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::DecryptResponse> {
+        self.inner.decrypt(req, options).await
+    }
+
+    async fn raw_encrypt(
+        &self,
+        req: crate::model::RawEncryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawEncryptResponse> {
+        self.inner.raw_encrypt(req, options).await
+    }
+
+    async fn raw_decrypt(
+        &self,
+        req: crate::model::RawDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::RawDecryptResponse> {
+        self.inner.raw_decrypt(req, options).await
+    }
+
+    async fn asymmetric_sign(
+        &self,
+        req: crate::model::AsymmetricSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricSignResponse> {
+        let name = req.name.clone();
+        let digest = digest_bytes(&req.digest).to_vec();
+        let lookup_options = options.clone();
+        let resp = self.inner.asymmetric_sign(req, options).await?;
+        if !resp.verified_digest_crc32c {
+            return Err(gax::error::Error::other(VerificationError::NotVerified {
+                field: "verified_digest_crc32c",
+            }));
+        }
+        let public_key = self.public_key(&name, &lookup_options).await?;
+        verify_signature(&public_key.pem, public_key.algorithm, &digest, &resp.signature)?;
+        Ok(resp)
+    }
+
+    async fn asymmetric_decrypt(
+        &self,
+        req: crate::model::AsymmetricDecryptRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::AsymmetricDecryptResponse> {
+        self.inner.asymmetric_decrypt(req, options).await
+    }
+
+    async fn mac_sign(
+        &self,
+        req: crate::model::MacSignRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacSignResponse> {
+        self.inner.mac_sign(req, options).await
+    }
+
+    async fn mac_verify(
+        &self,
+        req: crate::model::MacVerifyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::MacVerifyResponse> {
+        let name = req.name.clone();
+        let data = req.data.clone();
+        let mac = req.mac.clone();
+        let lookup_options = options.clone();
+        let resp = self.inner.mac_verify(req, options).await?;
+        if !resp.verified_data_crc32c {
+            return Err(gax::error::Error::other(VerificationError::NotVerified {
+                field: "verified_data_crc32c",
+            }));
+        }
+        if !resp.verified_mac_crc32c {
+            return Err(gax::error::Error::other(VerificationError::NotVerified {
+                field: "verified_mac_crc32c",
+            }));
+        }
+        if let Some(provider) = &self.local_mac_keys {
+            if let Some(key) = provider(&name) {
+                let algorithm = self.mac_algorithm(&name, &lookup_options).await?;
+                if !verify_hmac(algorithm, &key, &data, &mac)? || !resp.success {
+                    return Err(gax::error::Error::other(VerificationError::MacMismatch));
+                }
+            }
+        }
+        Ok(resp)
+    }
+
+    async fn generate_random_bytes(
+        &self,
+        req: crate::model::GenerateRandomBytesRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::GenerateRandomBytesResponse> {
+        self.inner.generate_random_bytes(req, options).await
+    }
+
+    async fn list_locations(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::ListLocationsResponse> {
+        self.inner.list_locations(req, options).await
+    }
+
+    async fn get_location(
+        &self,
+        req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<location::model::Location> {
+        self.inner.get_location(req, options).await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        self.inner.set_iam_policy(req, options).await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::Policy> {
+        self.inner.get_iam_policy(req, options).await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
+        self.inner.test_iam_permissions(req, options).await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<longrunning::model::Operation> {
+        self.inner.get_operation(req, options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::CryptoKeyVersionAlgorithm as Algorithm;
+
+    fn mac(algorithm: Algorithm, key: &[u8], data: &[u8]) -> Vec<u8> {
+        match algorithm {
+            Algorithm::HmacSha1 => {
+                let mut hmac = Hmac::<Sha1>::new_from_slice(key).unwrap();
+                hmac.update(data);
+                hmac.finalize().into_bytes().to_vec()
+            }
+            Algorithm::HmacSha224 => {
+                let mut hmac = Hmac::<Sha224>::new_from_slice(key).unwrap();
+                hmac.update(data);
+                hmac.finalize().into_bytes().to_vec()
+            }
+            Algorithm::HmacSha256 => {
+                let mut hmac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+                hmac.update(data);
+                hmac.finalize().into_bytes().to_vec()
+            }
+            Algorithm::HmacSha384 => {
+                let mut hmac = Hmac::<Sha384>::new_from_slice(key).unwrap();
+                hmac.update(data);
+                hmac.finalize().into_bytes().to_vec()
+            }
+            Algorithm::HmacSha512 => {
+                let mut hmac = Hmac::<Sha512>::new_from_slice(key).unwrap();
+                hmac.update(data);
+                hmac.finalize().into_bytes().to_vec()
+            }
+            other => panic!("unexpected algorithm {other:?}"),
+        }
+    }
+
+    #[test]
+    fn verify_hmac_dispatches_on_algorithm() {
+        let key = b"a shared secret";
+        let data = b"the data being MACed";
+        for algorithm in [
+            Algorithm::HmacSha1,
+            Algorithm::HmacSha224,
+            Algorithm::HmacSha256,
+            Algorithm::HmacSha384,
+            Algorithm::HmacSha512,
+        ] {
+            let tag = mac(algorithm, key, data);
+            assert!(
+                verify_hmac(algorithm, key, data, &tag).unwrap(),
+                "{algorithm:?} should verify its own tag"
+            );
+        }
+    }
+
+    #[test]
+    fn verify_hmac_rejects_tag_from_a_different_algorithm() {
+        let key = b"a shared secret";
+        let data = b"the data being MACed";
+        let sha256_tag = mac(Algorithm::HmacSha256, key, data);
+        // A tag computed with the wrong variant must not verify, even though
+        // both are valid HMAC tags over the same key and data.
+        assert!(!verify_hmac(Algorithm::HmacSha512, key, data, &sha256_tag).unwrap());
+    }
+
+    #[test]
+    fn verify_hmac_rejects_unsupported_algorithm() {
+        let result = verify_hmac(Algorithm::EcSignP256Sha256, b"key", b"data", b"mac");
+        assert!(result.is_err());
+    }
+}
@@ -23,6 +23,8 @@ use std::sync::Arc;
 
 const DEFAULT_HOST: &str = "https://cloud.googleapis.com/";
 
+const DEFAULT_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
 /// A `Result` alias where the `Err` case is an [Error].
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -30,6 +32,112 @@ struct InnerClient {
     http_client: reqwest::Client,
     cred: Credential,
     endpoint: String,
+    retry_policy: RetryPolicy,
+    tracing_enabled: bool,
+}
+
+/// Configures retries for transient failures in the requests made by a
+/// [LocationsClient].
+///
+/// Uses truncated exponential backoff with full jitter: for (0-based) attempt
+/// `n`, the delay is `min(max_backoff, initial_backoff * multiplier^n)`, and
+/// the actual wait is a uniformly random duration in `[0, delay]`. Only
+/// idempotent requests are retried, and only on 408/429/500/503/504
+/// responses or a transport-level send failure; any other 4xx stops
+/// immediately. A `Retry-After` response header, when present, is honored as
+/// a floor for the next delay.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: std::time::Duration,
+    max_backoff: std::time::Duration,
+    multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: std::time::Duration::from_millis(100),
+            max_backoff: std::time::Duration::from_secs(10),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns a default [RetryPolicy].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of attempts, including the first. A value of
+    /// `1` disables retries.
+    pub fn with_max_attempts(mut self, v: u32) -> Self {
+        self.max_attempts = v;
+        self
+    }
+
+    /// Sets the backoff delay used for the first retry.
+    pub fn with_initial_backoff(mut self, v: std::time::Duration) -> Self {
+        self.initial_backoff = v;
+        self
+    }
+
+    /// Sets the ceiling that the backoff delay never exceeds.
+    pub fn with_max_backoff(mut self, v: std::time::Duration) -> Self {
+        self.max_backoff = v;
+        self
+    }
+
+    /// Sets the multiplier applied to the backoff delay after each attempt.
+    pub fn with_multiplier(mut self, v: f64) -> Self {
+        self.multiplier = v;
+        self
+    }
+
+    fn is_retryable_status(status: u16) -> bool {
+        matches!(status, 408 | 429 | 500 | 503 | 504)
+    }
+
+    /// The truncated exponential backoff delay for (0-based) `attempt`,
+    /// before jitter is applied.
+    fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let scale = self.multiplier.powi(attempt as i32);
+        self.initial_backoff
+            .mul_f64(scale.max(0.0))
+            .min(self.max_backoff)
+    }
+
+    /// Applies full jitter: a uniformly random duration in `[0, delay]`.
+    fn jittered(delay: std::time::Duration) -> std::time::Duration {
+        delay.mul_f64(rand::random::<f64>())
+    }
+}
+
+/// Parses the `Retry-After` header as a number of seconds, ignoring the
+/// HTTP-date form.
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<std::time::Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    let seconds: u64 = value.trim().parse().ok()?;
+    Some(std::time::Duration::from_secs(seconds))
+}
+
+/// Where [ConfigBuilder] should obtain a [Credential] from, when one is not
+/// supplied directly.
+#[derive(Clone, Debug, Default)]
+enum CredentialSource {
+    /// Application Default Credentials: the ambient credential detected from
+    /// the environment (`GOOGLE_APPLICATION_CREDENTIALS`, gcloud, or the
+    /// metadata server).
+    #[default]
+    ApplicationDefault,
+    /// The JSON key file for a service account, identified by its path.
+    ServiceAccountFile(String),
+    /// An authorized-user (`gcloud auth login`) credential, as JSON.
+    AuthorizedUser(String),
+    /// The GCE/GKE metadata server.
+    MetadataServer,
 }
 
 #[derive(Default)]
@@ -37,6 +145,10 @@ pub struct ConfigBuilder {
     pub(crate) endpoint: Option<String>,
     pub(crate) client: Option<reqwest::Client>,
     pub(crate) cred: Option<Credential>,
+    credential_source: CredentialSource,
+    scopes: Option<Vec<String>>,
+    retry_policy: Option<RetryPolicy>,
+    tracing_enabled: Option<bool>,
 }
 
 impl ConfigBuilder {
@@ -51,21 +163,82 @@ impl ConfigBuilder {
         self
     }
 
+    /// Authenticates using the JSON key file for a service account, instead
+    /// of Application Default Credentials.
+    pub fn with_service_account_file<T: Into<String>>(mut self, path: T) -> Self {
+        self.credential_source = CredentialSource::ServiceAccountFile(path.into());
+        self
+    }
+
+    /// Authenticates using an authorized-user credential (e.g. the one
+    /// produced by `gcloud auth application-default login`), supplied as
+    /// JSON.
+    pub fn with_authorized_user<T: Into<String>>(mut self, json: T) -> Self {
+        self.credential_source = CredentialSource::AuthorizedUser(json.into());
+        self
+    }
+
+    /// Authenticates against the GCE/GKE metadata server, bypassing ADC
+    /// detection.
+    pub fn with_metadata_server(mut self) -> Self {
+        self.credential_source = CredentialSource::MetadataServer;
+        self
+    }
+
+    /// Overrides the OAuth scopes requested for the credential. Defaults to
+    /// `https://www.googleapis.com/auth/cloud-platform`.
+    pub fn with_scopes<T, V>(mut self, scopes: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.scopes = Some(scopes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the retry policy used for transient failures. Defaults to
+    /// [RetryPolicy::default].
+    pub fn set_retry_policy<T: Into<RetryPolicy>>(mut self, v: T) -> Self {
+        self.retry_policy = Some(v.into());
+        self
+    }
+
+    /// Enables or disables the `tracing` spans and events emitted by
+    /// [LocationsClient]'s requests. Enabled by default.
+    pub fn set_tracing_enabled(mut self, v: bool) -> Self {
+        self.tracing_enabled = Some(v);
+        self
+    }
+
     pub(crate) fn default_client() -> reqwest::Client {
         reqwest::Client::builder().build().unwrap()
     }
 
-    pub(crate) async fn default_credential() -> Result<Credential> {
-        let cc = CredentialConfig::builder()
-            .scopes(vec![
-                "https://www.googleapis.com/auth/cloud-platform".to_string()
-            ])
-            .build()
-            .map_err(Error::authentication)?;
-        Credential::find_default(cc)
-            .await
-            .map_err(Error::authentication)
+    pub(crate) fn tracing_enabled(&self) -> bool {
+        self.tracing_enabled.unwrap_or(true)
+    }
+}
+
+/// Builds a [Credential] from `source`, dispatching to the matching
+/// `google_cloud_auth` constructor, falling back to Application Default
+/// Credentials only when `source` is [CredentialSource::ApplicationDefault].
+async fn resolve_credential(
+    source: &CredentialSource,
+    scopes: Option<Vec<String>>,
+) -> Result<Credential> {
+    let cc = CredentialConfig::builder()
+        .scopes(scopes.unwrap_or_else(|| vec![DEFAULT_SCOPE.to_string()]))
+        .build()
+        .map_err(Error::authentication)?;
+    match source {
+        CredentialSource::ApplicationDefault => Credential::find_default(cc).await,
+        CredentialSource::ServiceAccountFile(path) => {
+            Credential::from_service_account_file(path, cc).await
+        }
+        CredentialSource::AuthorizedUser(json) => Credential::from_authorized_user(json, cc).await,
+        CredentialSource::MetadataServer => Credential::from_metadata_server(cc).await,
     }
+    .map_err(Error::authentication)
 }
 
 #[derive(serde::Serialize)]
@@ -86,27 +259,66 @@ impl LocationsClient {
     }
 
     pub async fn new_with_config(conf: ConfigBuilder) -> Result<Self> {
+        let tracing_enabled = conf.tracing_enabled();
+        let cred = match conf.cred {
+            Some(cred) => cred,
+            None => resolve_credential(&conf.credential_source, conf.scopes.clone()).await?,
+        };
         let inner = InnerClient {
             http_client: conf.client.unwrap_or(ConfigBuilder::default_client()),
-            cred: conf
-                .cred
-                .unwrap_or(ConfigBuilder::default_credential().await?),
+            cred,
             endpoint: conf.endpoint.unwrap_or(DEFAULT_HOST.to_string()),
+            retry_policy: conf.retry_policy.unwrap_or_default(),
+            tracing_enabled,
         };
         Ok(Self {
             inner: Arc::new(inner),
         })
     }
 
+    /// Lists information about the supported locations for this service,
+    /// automatically following `next_page_token` and flattening every page
+    /// into a single stream of [Location][crate::model::Location]s.
+    ///
+    /// This avoids the need to manually thread `page_token` through repeated
+    /// calls to [list_locations][Self::list_locations].
+    pub fn list_locations_stream(
+        &self,
+        req: crate::model::ListLocationsRequest,
+    ) -> impl futures::Stream<Item = Result<crate::model::Location>> + '_ {
+        use futures::stream::StreamExt;
+        futures::stream::unfold(Some(req), move |state| async move {
+            let req = state?;
+            let resp = match self.list_locations(req.clone()).await {
+                Ok(resp) => resp,
+                Err(e) => return Some((Err(e), None)),
+            };
+            let next = if resp.next_page_token.is_empty() {
+                None
+            } else {
+                Some(crate::model::ListLocationsRequest {
+                    page_token: resp.next_page_token,
+                    ..req
+                })
+            };
+            Some((Ok(resp.locations), next))
+        })
+        .flat_map(|page| match page {
+            Ok(locations) => futures::stream::iter(locations.into_iter().map(Ok)).left_stream(),
+            Err(e) => futures::stream::iter(vec![Err(e)]).right_stream(),
+        })
+    }
+
     /// Lists information about the supported locations for this service.
     pub async fn list_locations(
         &self,
         req: crate::model::ListLocationsRequest,
     ) -> Result<crate::model::ListLocationsResponse> {
         let inner_client = self.inner.clone();
+        let url = format!("{}/v1/{}", inner_client.endpoint, req.name);
         let builder = inner_client
             .http_client
-            .get(format!("{}/v1/{}", inner_client.endpoint, req.name,))
+            .get(&url)
             .query(&[("alt", "json")]);
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
@@ -114,7 +326,15 @@ impl LocationsClient {
             gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token)
             .map_err(Error::other)?;
-        self.execute(builder, None::<NoBody>).await
+        self.execute(
+            builder,
+            None::<NoBody>,
+            true,
+            "ListLocations",
+            &url,
+            Some(&req.page_token),
+        )
+        .await
     }
 
     /// Gets information about a location.
@@ -123,38 +343,104 @@ impl LocationsClient {
         req: crate::model::GetLocationRequest,
     ) -> Result<crate::model::Location> {
         let inner_client = self.inner.clone();
+        let url = format!("{}/v1/{}", inner_client.endpoint, req.name);
         let builder = inner_client
             .http_client
-            .get(format!("{}/v1/{}", inner_client.endpoint, req.name,))
+            .get(&url)
             .query(&[("alt", "json")]);
-        self.execute(builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, true, "GetLocation", &url, None)
+            .await
     }
 
+    /// Sends `builder`, retrying transient failures per
+    /// [RetryPolicy][RetryPolicy] when `idempotent` is `true`.
+    ///
+    /// Always emits a `debug`-level span carrying `rpc`, `url`, and
+    /// `page_token` (a subscriber can still filter it out by level). The
+    /// events inside it -- a `debug` event when the bearer token is
+    /// attached, and a completion event recording the HTTP status and
+    /// elapsed time, `debug` on success or `warn` on a non-2xx response --
+    /// are only emitted when
+    /// [tracing is enabled][ConfigBuilder::set_tracing_enabled].
+    #[tracing::instrument(
+        level = "debug",
+        skip(self, builder, body),
+        fields(rpc = %rpc, url = %url, page_token = page_token.unwrap_or_default())
+    )]
+    #[allow(clippy::too_many_arguments)]
     async fn execute<I: serde::ser::Serialize, O: serde::de::DeserializeOwned>(
         &self,
-        mut builder: reqwest::RequestBuilder,
+        builder: reqwest::RequestBuilder,
         body: Option<I>,
+        idempotent: bool,
+        rpc: &str,
+        url: &str,
+        page_token: Option<&str>,
     ) -> Result<O> {
         let inner_client = self.inner.clone();
-        builder = builder.bearer_auth(
-            &inner_client
-                .cred
-                .access_token()
-                .await
-                .map_err(Error::authentication)?
-                .value,
-        );
-        if let Some(body) = body {
-            builder = builder.json(&body);
+        let tracing_enabled = inner_client.tracing_enabled;
+        let token = inner_client
+            .cred
+            .access_token()
+            .await
+            .map_err(Error::authentication)?
+            .value;
+        if tracing_enabled {
+            tracing::debug!("attached bearer token");
         }
-        let resp = builder.send().await.map_err(Error::io)?;
-        if !resp.status().is_success() {
-            let status = resp.status().as_u16();
-            let headers = gax::error::convert_headers(resp.headers());
-            let body = resp.bytes().await.map_err(Error::io)?;
-            return Err(HttpError::new(status, headers, Some(body)).into());
+        let max_attempts = inner_client.retry_policy.max_attempts.max(1);
+        let mut next_delay = None;
+        let start = std::time::Instant::now();
+        for attempt in 0..max_attempts {
+            if let Some(delay) = next_delay.take() {
+                tokio::time::sleep(delay).await;
+            }
+            let mut req = builder
+                .try_clone()
+                .expect("request body must be cloneable to support retries")
+                .bearer_auth(&token);
+            if let Some(body) = &body {
+                req = req.json(body);
+            }
+            let last_attempt = attempt + 1 == max_attempts;
+            match req.send().await {
+                Ok(resp) if resp.status().is_success() => {
+                    if tracing_enabled {
+                        tracing::debug!(
+                            status = resp.status().as_u16(),
+                            elapsed_ms = start.elapsed().as_millis() as u64,
+                            "request succeeded"
+                        );
+                    }
+                    return resp.json::<O>().await.map_err(Error::serde);
+                }
+                Ok(resp) => {
+                    let status = resp.status().as_u16();
+                    let headers = gax::error::convert_headers(resp.headers());
+                    let retry_after = parse_retry_after(resp.headers());
+                    let body = resp.bytes().await.map_err(Error::io)?;
+                    let err = HttpError::new(status, headers, Some(body));
+                    if tracing_enabled {
+                        tracing::warn!(
+                            status,
+                            elapsed_ms = start.elapsed().as_millis() as u64,
+                            "request failed"
+                        );
+                    }
+                    if last_attempt || !idempotent || !RetryPolicy::is_retryable_status(status) {
+                        return Err(err.into());
+                    }
+                    let backoff = RetryPolicy::jittered(inner_client.retry_policy.backoff(attempt));
+                    next_delay = Some(retry_after.map_or(backoff, |floor| backoff.max(floor)));
+                }
+                Err(e) => {
+                    if last_attempt || !idempotent {
+                        return Err(Error::io(e));
+                    }
+                    next_delay = Some(RetryPolicy::jittered(inner_client.retry_policy.backoff(attempt)));
+                }
+            }
         }
-        let response = resp.json::<O>().await.map_err(Error::serde)?;
-        Ok(response)
+        unreachable!("loop always returns before exhausting its range")
     }
 }
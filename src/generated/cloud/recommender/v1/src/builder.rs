@@ -16,6 +16,116 @@
 
 pub mod recommender {
     use crate::Result;
+    use gax::polling_backoff_policy::PollingBackoffPolicy;
+
+    /// The default number of attempts made by builders that opt into
+    /// `.with_etag_refresh(true)`, e.g. [UpdateRecommenderConfig::with_etag_refresh].
+    const DEFAULT_ETAG_REFRESH_ATTEMPTS: u32 = 3;
+
+    /// Whether `error` is an etag-mismatch conflict (`ABORTED`/409 or
+    /// `FAILED_PRECONDITION`/412) worth retrying after a refresh, as opposed
+    /// to a permanent error (e.g. `PERMISSION_DENIED`, `NOT_FOUND`,
+    /// `INVALID_ARGUMENT`) that should surface immediately instead of
+    /// burning through the remaining attempts.
+    fn is_etag_conflict(error: &gax::error::Error) -> bool {
+        matches!(error.http_status_code(), Some(409) | Some(412))
+    }
+
+    /// Implemented by typed views over an [Insight][crate::model::Insight]'s
+    /// `content`, keyed by the insight's `insight_subtype`.
+    ///
+    /// Register a mapping by implementing this trait for a `serde::Deserialize`
+    /// type and passing it to [GetInsight::send_typed].
+    pub trait InsightContent: serde::de::DeserializeOwned {
+        /// The `insight_subtype` this type decodes, e.g. `"google.iam.policy.Insight"`.
+        const SUBTYPE: &'static str;
+    }
+
+    /// Implemented by typed views over a [Recommendation][crate::model::Recommendation]'s
+    /// `content`, keyed by the recommendation's `recommender_subtype`.
+    ///
+    /// Register a mapping by implementing this trait for a `serde::Deserialize`
+    /// type and passing it to [GetRecommendation::send_typed].
+    pub trait RecommendationContent: serde::de::DeserializeOwned {
+        /// The `recommender_subtype` this type decodes.
+        const SUBTYPE: &'static str;
+    }
+
+    /// Extension trait adding typed, subtype-checked decoding of an
+    /// [Insight][crate::model::Insight]'s `content` field, without requiring a
+    /// fresh `GetInsight` call (see [GetInsight::send_typed] for that).
+    pub trait InsightContentExt {
+        /// Deserializes `content` into `T`, erroring if `insight_subtype` does
+        /// not match [T::SUBTYPE][InsightContent::SUBTYPE].
+        fn content_as<T: InsightContent>(&self) -> Result<T>;
+    }
+
+    impl InsightContentExt for crate::model::Insight {
+        fn content_as<T: InsightContent>(&self) -> Result<T> {
+            if self.insight_subtype != T::SUBTYPE {
+                return Err(gax::error::Error::other(format!(
+                    "insight_subtype mismatch: expected `{}`, got `{}`",
+                    T::SUBTYPE,
+                    self.insight_subtype
+                )));
+            }
+            let value = serde_json::to_value(&self.content).map_err(gax::error::Error::serde)?;
+            serde_json::from_value(value).map_err(gax::error::Error::serde)
+        }
+    }
+
+    /// Extension trait adding typed, subtype-checked decoding of a
+    /// [Recommendation][crate::model::Recommendation]'s `content` field,
+    /// without requiring a fresh `GetRecommendation` call (see
+    /// [GetRecommendation::send_typed] for that).
+    pub trait RecommendationContentExt {
+        /// Deserializes `content` into `T`, erroring if `recommender_subtype`
+        /// does not match [T::SUBTYPE][RecommendationContent::SUBTYPE].
+        fn content_as<T: RecommendationContent>(&self) -> Result<T>;
+    }
+
+    impl RecommendationContentExt for crate::model::Recommendation {
+        fn content_as<T: RecommendationContent>(&self) -> Result<T> {
+            if self.recommender_subtype != T::SUBTYPE {
+                return Err(gax::error::Error::other(format!(
+                    "recommender_subtype mismatch: expected `{}`, got `{}`",
+                    T::SUBTYPE,
+                    self.recommender_subtype
+                )));
+            }
+            let value = serde_json::to_value(&self.content).map_err(gax::error::Error::serde)?;
+            serde_json::from_value(value).map_err(gax::error::Error::serde)
+        }
+    }
+
+    /// Well-known [InsightContent] for the `google.iam.policy.Insight` subtype.
+    #[derive(Clone, Debug, serde::Deserialize)]
+    pub struct IamPolicyInsight {
+        #[serde(default, rename = "exposedPermissionsCount")]
+        pub exposed_permissions_count: i64,
+        #[serde(default, rename = "grantedPermissionsCount")]
+        pub granted_permissions_count: i64,
+        #[serde(default, rename = "inferredPermissionsCount")]
+        pub inferred_permissions_count: i64,
+    }
+
+    impl InsightContent for IamPolicyInsight {
+        const SUBTYPE: &'static str = "google.iam.policy.Insight";
+    }
+
+    /// Well-known [RecommendationContent] for the
+    /// `google.compute.instance.MachineTypeRecommender` subtype.
+    #[derive(Clone, Debug, serde::Deserialize)]
+    pub struct VmRightsizingRecommendation {
+        #[serde(default, rename = "currentMachineType")]
+        pub current_machine_type: std::string::String,
+        #[serde(default, rename = "recommendedMachineType")]
+        pub recommended_machine_type: std::string::String,
+    }
+
+    impl RecommendationContent for VmRightsizingRecommendation {
+        const SUBTYPE: &'static str = "google.compute.instance.MachineTypeRecommender";
+    }
 
     /// A builder for [Recommender][super::super::client::Recommender].
     ///
@@ -114,6 +224,51 @@ pub mod recommender {
             gax::paginator::internal::new_paginator(token, execute)
         }
 
+        /// Streams individual [Insight][crate::model::Insight] values, fetching
+        /// additional pages as the current page's items are exhausted.
+        pub async fn items(
+            self,
+        ) -> impl gax::paginator::ItemPaginator<crate::model::ListInsightsResponse, gax::error::Error>
+        {
+            use gax::paginator::Paginator;
+            self.paginator().await.items()
+        }
+
+        /// Streams each [ListInsightsResponse][crate::model::ListInsightsResponse]
+        /// page, issuing the next request with `page_token` set to the previous
+        /// page's `next_page_token` until that token is empty.
+        pub fn by_page(
+            self,
+        ) -> impl futures::Stream<Item = Result<crate::model::ListInsightsResponse>> {
+            futures::stream::unfold(Some(self), move |state| async move {
+                let builder = state?;
+                let result = builder.clone().send().await;
+                match result {
+                    Ok(response) => {
+                        let next = if response.next_page_token.is_empty() {
+                            None
+                        } else {
+                            let mut next_builder = builder;
+                            next_builder.0.request.page_token = response.next_page_token.clone();
+                            Some(next_builder)
+                        };
+                        Some((Ok(response), next))
+                    }
+                    Err(e) => Some((Err(e), None)),
+                }
+            })
+        }
+
+        /// Streams individual [Insight][crate::model::Insight] values, flattening
+        /// each [by_page][Self::by_page] response's `insights` field.
+        pub fn by_item(self) -> impl futures::Stream<Item = Result<crate::model::Insight>> {
+            use futures::stream::StreamExt;
+            self.by_page().flat_map(|page| match page {
+                Ok(page) => futures::stream::iter(page.insights.into_iter().map(Ok)).left_stream(),
+                Err(e) => futures::stream::iter(vec![Err(e)]).right_stream(),
+            })
+        }
+
         /// Sets the value of [parent][crate::model::ListInsightsRequest::parent].
         ///
         /// This is a **required** field for requests.
@@ -179,6 +334,22 @@ pub mod recommender {
                 .map(gax::response::Response::into_body)
         }
 
+        /// Sends the request and deserializes the returned [Insight][crate::model::Insight]'s
+        /// `content` into `T`, after checking that `insight_subtype` matches
+        /// [T::SUBTYPE][InsightContent::SUBTYPE].
+        pub async fn send_typed<T: InsightContent>(self) -> Result<T> {
+            let insight = self.send().await?;
+            if insight.insight_subtype != T::SUBTYPE {
+                return Err(gax::error::Error::other(format!(
+                    "insight_subtype mismatch: expected `{}`, got `{}`",
+                    T::SUBTYPE,
+                    insight.insight_subtype
+                )));
+            }
+            let value = serde_json::to_value(&insight.content).map_err(gax::error::Error::serde)?;
+            serde_json::from_value(value).map_err(gax::error::Error::serde)
+        }
+
         /// Sets the value of [name][crate::model::GetInsightRequest::name].
         ///
         /// This is a **required** field for requests.
@@ -229,6 +400,55 @@ pub mod recommender {
                 .map(gax::response::Response::into_body)
         }
 
+        /// Sends the request, resolving the [etag][crate::model::MarkInsightAcceptedRequest::etag]
+        /// automatically when it has not been set.
+        ///
+        /// If no etag was set on this builder, fetches the current [Insight][crate::model::Insight]
+        /// via `GetInsight` and copies its etag into the request before calling
+        /// `mark_insight_accepted`. If the mark call fails with an etag-mismatch
+        /// error (`ABORTED`/409 or `FAILED_PRECONDITION`/412), the insight is
+        /// re-fetched and the call retried, waiting an
+        /// [ExponentialBackoff][gax::polling_backoff_policy::ExponentialBackoff]
+        /// period between attempts, up to `max_attempts` total attempts. Any
+        /// other error returns immediately without consuming the remaining
+        /// attempts.
+        pub async fn send_with_refresh(self, max_attempts: u32) -> Result<crate::model::Insight> {
+            let stub = self.0.stub.clone();
+            let options = self.0.options.clone();
+            let mut request = self.0.request;
+            let backoff = gax::polling_backoff_policy::ExponentialBackoff::new();
+            let loop_start = std::time::Instant::now();
+            for attempt in 1..=max_attempts.max(1) {
+                if request.etag.is_empty() || attempt > 1 {
+                    let insight = (*stub)
+                        .get_insight(
+                            crate::model::GetInsightRequest {
+                                name: request.name.clone(),
+                                ..Default::default()
+                            },
+                            options.clone(),
+                        )
+                        .await
+                        .map(gax::response::Response::into_body)?;
+                    request.etag = insight.etag;
+                }
+                match (*stub)
+                    .mark_insight_accepted(request.clone(), options.clone())
+                    .await
+                    .map(gax::response::Response::into_body)
+                {
+                    Ok(insight) => return Ok(insight),
+                    Err(e) if attempt < max_attempts.max(1) && is_etag_conflict(&e) => {
+                        let wait = backoff.wait_period(loop_start, attempt);
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            unreachable!("loop always returns before exhausting its range")
+        }
+
         /// Sets the value of [name][crate::model::MarkInsightAcceptedRequest::name].
         ///
         /// This is a **required** field for requests.
@@ -314,6 +534,57 @@ pub mod recommender {
             gax::paginator::internal::new_paginator(token, execute)
         }
 
+        /// Streams individual [Recommendation][crate::model::Recommendation]
+        /// values, fetching additional pages as the current page's items are
+        /// exhausted.
+        pub async fn items(
+            self,
+        ) -> impl gax::paginator::ItemPaginator<
+            crate::model::ListRecommendationsResponse,
+            gax::error::Error,
+        > {
+            use gax::paginator::Paginator;
+            self.paginator().await.items()
+        }
+
+        /// Streams each [ListRecommendationsResponse][crate::model::ListRecommendationsResponse]
+        /// page, issuing the next request with `page_token` set to the previous
+        /// page's `next_page_token` until that token is empty.
+        pub fn by_page(
+            self,
+        ) -> impl futures::Stream<Item = Result<crate::model::ListRecommendationsResponse>> {
+            futures::stream::unfold(Some(self), move |state| async move {
+                let builder = state?;
+                let result = builder.clone().send().await;
+                match result {
+                    Ok(response) => {
+                        let next = if response.next_page_token.is_empty() {
+                            None
+                        } else {
+                            let mut next_builder = builder;
+                            next_builder.0.request.page_token = response.next_page_token.clone();
+                            Some(next_builder)
+                        };
+                        Some((Ok(response), next))
+                    }
+                    Err(e) => Some((Err(e), None)),
+                }
+            })
+        }
+
+        /// Streams individual [Recommendation][crate::model::Recommendation]
+        /// values, flattening each [by_page][Self::by_page] response's
+        /// `recommendations` field.
+        pub fn by_item(self) -> impl futures::Stream<Item = Result<crate::model::Recommendation>> {
+            use futures::stream::StreamExt;
+            self.by_page().flat_map(|page| match page {
+                Ok(page) => {
+                    futures::stream::iter(page.recommendations.into_iter().map(Ok)).left_stream()
+                }
+                Err(e) => futures::stream::iter(vec![Err(e)]).right_stream(),
+            })
+        }
+
         /// Sets the value of [parent][crate::model::ListRecommendationsRequest::parent].
         ///
         /// This is a **required** field for requests.
@@ -382,6 +653,24 @@ pub mod recommender {
                 .map(gax::response::Response::into_body)
         }
 
+        /// Sends the request and deserializes the returned
+        /// [Recommendation][crate::model::Recommendation]'s `content` into `T`,
+        /// after checking that `recommender_subtype` matches
+        /// [T::SUBTYPE][RecommendationContent::SUBTYPE].
+        pub async fn send_typed<T: RecommendationContent>(self) -> Result<T> {
+            let recommendation = self.send().await?;
+            if recommendation.recommender_subtype != T::SUBTYPE {
+                return Err(gax::error::Error::other(format!(
+                    "recommender_subtype mismatch: expected `{}`, got `{}`",
+                    T::SUBTYPE,
+                    recommendation.recommender_subtype
+                )));
+            }
+            let value = serde_json::to_value(&recommendation.content)
+                .map_err(gax::error::Error::serde)?;
+            serde_json::from_value(value).map_err(gax::error::Error::serde)
+        }
+
         /// Sets the value of [name][crate::model::GetRecommendationRequest::name].
         ///
         /// This is a **required** field for requests.
@@ -492,6 +781,59 @@ pub mod recommender {
                 .map(gax::response::Response::into_body)
         }
 
+        /// Sends the request, resolving the [etag][crate::model::MarkRecommendationClaimedRequest::etag]
+        /// automatically when it has not been set.
+        ///
+        /// If no etag was set on this builder, fetches the current
+        /// [Recommendation][crate::model::Recommendation] via `GetRecommendation`
+        /// and copies its etag into the request before calling
+        /// `mark_recommendation_claimed`. If the mark call fails with an
+        /// etag-mismatch error (`ABORTED`/409 or `FAILED_PRECONDITION`/412),
+        /// the recommendation is re-fetched and the call retried, waiting an
+        /// [ExponentialBackoff][gax::polling_backoff_policy::ExponentialBackoff]
+        /// period between attempts, up to `max_attempts` total attempts. Any
+        /// other error returns immediately without consuming the remaining
+        /// attempts.
+        pub async fn send_with_refresh(
+            self,
+            max_attempts: u32,
+        ) -> Result<crate::model::Recommendation> {
+            let stub = self.0.stub.clone();
+            let options = self.0.options.clone();
+            let mut request = self.0.request;
+            let backoff = gax::polling_backoff_policy::ExponentialBackoff::new();
+            let loop_start = std::time::Instant::now();
+            for attempt in 1..=max_attempts.max(1) {
+                if request.etag.is_empty() || attempt > 1 {
+                    let recommendation = (*stub)
+                        .get_recommendation(
+                            crate::model::GetRecommendationRequest {
+                                name: request.name.clone(),
+                                ..Default::default()
+                            },
+                            options.clone(),
+                        )
+                        .await
+                        .map(gax::response::Response::into_body)?;
+                    request.etag = recommendation.etag;
+                }
+                match (*stub)
+                    .mark_recommendation_claimed(request.clone(), options.clone())
+                    .await
+                    .map(gax::response::Response::into_body)
+                {
+                    Ok(recommendation) => return Ok(recommendation),
+                    Err(e) if attempt < max_attempts.max(1) && is_etag_conflict(&e) => {
+                        let wait = backoff.wait_period(loop_start, attempt);
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            unreachable!("loop always returns before exhausting its range")
+        }
+
         /// Sets the value of [name][crate::model::MarkRecommendationClaimedRequest::name].
         ///
         /// This is a **required** field for requests.
@@ -564,6 +906,59 @@ pub mod recommender {
                 .map(gax::response::Response::into_body)
         }
 
+        /// Sends the request, resolving the [etag][crate::model::MarkRecommendationSucceededRequest::etag]
+        /// automatically when it has not been set.
+        ///
+        /// If no etag was set on this builder, fetches the current
+        /// [Recommendation][crate::model::Recommendation] via `GetRecommendation`
+        /// and copies its etag into the request before calling
+        /// `mark_recommendation_succeeded`. If the mark call fails with an
+        /// etag-mismatch error (`ABORTED`/409 or `FAILED_PRECONDITION`/412),
+        /// the recommendation is re-fetched and the call retried, waiting an
+        /// [ExponentialBackoff][gax::polling_backoff_policy::ExponentialBackoff]
+        /// period between attempts, up to `max_attempts` total attempts. Any
+        /// other error returns immediately without consuming the remaining
+        /// attempts.
+        pub async fn send_with_refresh(
+            self,
+            max_attempts: u32,
+        ) -> Result<crate::model::Recommendation> {
+            let stub = self.0.stub.clone();
+            let options = self.0.options.clone();
+            let mut request = self.0.request;
+            let backoff = gax::polling_backoff_policy::ExponentialBackoff::new();
+            let loop_start = std::time::Instant::now();
+            for attempt in 1..=max_attempts.max(1) {
+                if request.etag.is_empty() || attempt > 1 {
+                    let recommendation = (*stub)
+                        .get_recommendation(
+                            crate::model::GetRecommendationRequest {
+                                name: request.name.clone(),
+                                ..Default::default()
+                            },
+                            options.clone(),
+                        )
+                        .await
+                        .map(gax::response::Response::into_body)?;
+                    request.etag = recommendation.etag;
+                }
+                match (*stub)
+                    .mark_recommendation_succeeded(request.clone(), options.clone())
+                    .await
+                    .map(gax::response::Response::into_body)
+                {
+                    Ok(recommendation) => return Ok(recommendation),
+                    Err(e) if attempt < max_attempts.max(1) && is_etag_conflict(&e) => {
+                        let wait = backoff.wait_period(loop_start, attempt);
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            unreachable!("loop always returns before exhausting its range")
+        }
+
         /// Sets the value of [name][crate::model::MarkRecommendationSucceededRequest::name].
         ///
         /// This is a **required** field for requests.
@@ -604,13 +999,14 @@ pub mod recommender {
     #[derive(Clone, Debug)]
     pub struct MarkRecommendationFailed(
         RequestBuilder<crate::model::MarkRecommendationFailedRequest>,
+        bool,
     );
 
     impl MarkRecommendationFailed {
         pub(crate) fn new(
             stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
         ) -> Self {
-            Self(RequestBuilder::new(stub))
+            Self(RequestBuilder::new(stub), false)
         }
 
         /// Sets the full request, replacing any prior values.
@@ -628,14 +1024,80 @@ pub mod recommender {
             self
         }
 
+        /// When set, [send][Self::send] transparently retries an
+        /// etag-mismatch failure by re-fetching the current
+        /// [Recommendation][crate::model::Recommendation] and resubmitting
+        /// with its etag, up to a bounded number of attempts. Equivalent to
+        /// calling [send_with_refresh][Self::send_with_refresh] directly.
+        pub fn with_etag_refresh(mut self, v: bool) -> Self {
+            self.1 = v;
+            self
+        }
+
         /// Sends the request.
         pub async fn send(self) -> Result<crate::model::Recommendation> {
+            if self.1 {
+                return self.send_with_refresh(DEFAULT_ETAG_REFRESH_ATTEMPTS).await;
+            }
             (*self.0.stub)
                 .mark_recommendation_failed(self.0.request, self.0.options)
                 .await
                 .map(gax::response::Response::into_body)
         }
 
+        /// Sends the request, resolving the [etag][crate::model::MarkRecommendationFailedRequest::etag]
+        /// automatically when it has not been set.
+        ///
+        /// If no etag was set on this builder, fetches the current
+        /// [Recommendation][crate::model::Recommendation] via `GetRecommendation`
+        /// and copies its etag into the request before calling
+        /// `mark_recommendation_failed`. If the mark call fails with an
+        /// etag-mismatch error (`ABORTED`/409 or `FAILED_PRECONDITION`/412),
+        /// the recommendation is re-fetched and the call retried, waiting an
+        /// [ExponentialBackoff][gax::polling_backoff_policy::ExponentialBackoff]
+        /// period between attempts, up to `max_attempts` total attempts. Any
+        /// other error returns immediately without consuming the remaining
+        /// attempts.
+        pub async fn send_with_refresh(
+            self,
+            max_attempts: u32,
+        ) -> Result<crate::model::Recommendation> {
+            let stub = self.0.stub.clone();
+            let options = self.0.options.clone();
+            let mut request = self.0.request;
+            let backoff = gax::polling_backoff_policy::ExponentialBackoff::new();
+            let loop_start = std::time::Instant::now();
+            for attempt in 1..=max_attempts.max(1) {
+                if request.etag.is_empty() || attempt > 1 {
+                    let recommendation = (*stub)
+                        .get_recommendation(
+                            crate::model::GetRecommendationRequest {
+                                name: request.name.clone(),
+                                ..Default::default()
+                            },
+                            options.clone(),
+                        )
+                        .await
+                        .map(gax::response::Response::into_body)?;
+                    request.etag = recommendation.etag;
+                }
+                match (*stub)
+                    .mark_recommendation_failed(request.clone(), options.clone())
+                    .await
+                    .map(gax::response::Response::into_body)
+                {
+                    Ok(recommendation) => return Ok(recommendation),
+                    Err(e) if attempt < max_attempts.max(1) && is_etag_conflict(&e) => {
+                        let wait = backoff.wait_period(loop_start, attempt);
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            unreachable!("loop always returns before exhausting its range")
+        }
+
         /// Sets the value of [name][crate::model::MarkRecommendationFailedRequest::name].
         ///
         /// This is a **required** field for requests.
@@ -726,13 +1188,14 @@ pub mod recommender {
     #[derive(Clone, Debug)]
     pub struct UpdateRecommenderConfig(
         RequestBuilder<crate::model::UpdateRecommenderConfigRequest>,
+        bool,
     );
 
     impl UpdateRecommenderConfig {
         pub(crate) fn new(
             stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
         ) -> Self {
-            Self(RequestBuilder::new(stub))
+            Self(RequestBuilder::new(stub), false)
         }
 
         /// Sets the full request, replacing any prior values.
@@ -750,14 +1213,82 @@ pub mod recommender {
             self
         }
 
+        /// When set, [send][Self::send] transparently retries an
+        /// ABORTED/FAILED_PRECONDITION etag-mismatch failure by re-fetching
+        /// the current [RecommenderConfig][crate::model::RecommenderConfig]
+        /// via `GetRecommenderConfig`, copying its etag into the request, and
+        /// resubmitting with the same
+        /// [update_mask][crate::model::UpdateRecommenderConfigRequest::update_mask]
+        /// so only the intended fields change, up to a bounded number of
+        /// attempts.
+        pub fn with_etag_refresh(mut self, v: bool) -> Self {
+            self.1 = v;
+            self
+        }
+
         /// Sends the request.
         pub async fn send(self) -> Result<crate::model::RecommenderConfig> {
+            if self.1 {
+                return self.send_with_refresh(DEFAULT_ETAG_REFRESH_ATTEMPTS).await;
+            }
             (*self.0.stub)
                 .update_recommender_config(self.0.request, self.0.options)
                 .await
                 .map(gax::response::Response::into_body)
         }
 
+        /// Sends the request, refreshing the etag on an etag-mismatch
+        /// (`ABORTED`/409 or `FAILED_PRECONDITION`/412) failure, waiting an
+        /// [ExponentialBackoff][gax::polling_backoff_policy::ExponentialBackoff]
+        /// period between attempts. Any other error returns immediately
+        /// without consuming the remaining attempts.
+        ///
+        /// See [with_etag_refresh][Self::with_etag_refresh] for details.
+        async fn send_with_refresh(
+            self,
+            max_attempts: u32,
+        ) -> Result<crate::model::RecommenderConfig> {
+            let stub = self.0.stub.clone();
+            let options = self.0.options.clone();
+            let mut request = self.0.request;
+            let backoff = gax::polling_backoff_policy::ExponentialBackoff::new();
+            let loop_start = std::time::Instant::now();
+            for attempt in 1..=max_attempts.max(1) {
+                match (*stub)
+                    .update_recommender_config(request.clone(), options.clone())
+                    .await
+                    .map(gax::response::Response::into_body)
+                {
+                    Ok(config) => return Ok(config),
+                    Err(e) if attempt < max_attempts.max(1) && is_etag_conflict(&e) => {
+                        let name = request
+                            .recommender_config
+                            .as_ref()
+                            .map(|c| c.name.clone())
+                            .unwrap_or_default();
+                        let current = (*stub)
+                            .get_recommender_config(
+                                crate::model::GetRecommenderConfigRequest {
+                                    name,
+                                    ..Default::default()
+                                },
+                                options.clone(),
+                            )
+                            .await
+                            .map(gax::response::Response::into_body)?;
+                        if let Some(config) = request.recommender_config.as_mut() {
+                            config.etag = current.etag;
+                        }
+                        let wait = backoff.wait_period(loop_start, attempt);
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            unreachable!("loop always returns before exhausting its range")
+        }
+
         /// Sets the value of [recommender_config][crate::model::UpdateRecommenderConfigRequest::recommender_config].
         ///
         /// This is a **required** field for requests.
@@ -848,13 +1379,14 @@ pub mod recommender {
     #[derive(Clone, Debug)]
     pub struct UpdateInsightTypeConfig(
         RequestBuilder<crate::model::UpdateInsightTypeConfigRequest>,
+        bool,
     );
 
     impl UpdateInsightTypeConfig {
         pub(crate) fn new(
             stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
         ) -> Self {
-            Self(RequestBuilder::new(stub))
+            Self(RequestBuilder::new(stub), false)
         }
 
         /// Sets the full request, replacing any prior values.
@@ -872,14 +1404,82 @@ pub mod recommender {
             self
         }
 
+        /// When set, [send][Self::send] transparently retries an
+        /// ABORTED/FAILED_PRECONDITION etag-mismatch failure by re-fetching
+        /// the current [InsightTypeConfig][crate::model::InsightTypeConfig]
+        /// via `GetInsightTypeConfig`, copying its etag into the request, and
+        /// resubmitting with the same
+        /// [update_mask][crate::model::UpdateInsightTypeConfigRequest::update_mask]
+        /// so only the intended fields change, up to a bounded number of
+        /// attempts.
+        pub fn with_etag_refresh(mut self, v: bool) -> Self {
+            self.1 = v;
+            self
+        }
+
         /// Sends the request.
         pub async fn send(self) -> Result<crate::model::InsightTypeConfig> {
+            if self.1 {
+                return self.send_with_refresh(DEFAULT_ETAG_REFRESH_ATTEMPTS).await;
+            }
             (*self.0.stub)
                 .update_insight_type_config(self.0.request, self.0.options)
                 .await
                 .map(gax::response::Response::into_body)
         }
 
+        /// Sends the request, refreshing the etag on an etag-mismatch
+        /// (`ABORTED`/409 or `FAILED_PRECONDITION`/412) failure, waiting an
+        /// [ExponentialBackoff][gax::polling_backoff_policy::ExponentialBackoff]
+        /// period between attempts. Any other error returns immediately
+        /// without consuming the remaining attempts.
+        ///
+        /// See [with_etag_refresh][Self::with_etag_refresh] for details.
+        async fn send_with_refresh(
+            self,
+            max_attempts: u32,
+        ) -> Result<crate::model::InsightTypeConfig> {
+            let stub = self.0.stub.clone();
+            let options = self.0.options.clone();
+            let mut request = self.0.request;
+            let backoff = gax::polling_backoff_policy::ExponentialBackoff::new();
+            let loop_start = std::time::Instant::now();
+            for attempt in 1..=max_attempts.max(1) {
+                match (*stub)
+                    .update_insight_type_config(request.clone(), options.clone())
+                    .await
+                    .map(gax::response::Response::into_body)
+                {
+                    Ok(config) => return Ok(config),
+                    Err(e) if attempt < max_attempts.max(1) && is_etag_conflict(&e) => {
+                        let name = request
+                            .insight_type_config
+                            .as_ref()
+                            .map(|c| c.name.clone())
+                            .unwrap_or_default();
+                        let current = (*stub)
+                            .get_insight_type_config(
+                                crate::model::GetInsightTypeConfigRequest {
+                                    name,
+                                    ..Default::default()
+                                },
+                                options.clone(),
+                            )
+                            .await
+                            .map(gax::response::Response::into_body)?;
+                        if let Some(config) = request.insight_type_config.as_mut() {
+                            config.etag = current.etag;
+                        }
+                        let wait = backoff.wait_period(loop_start, attempt);
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            unreachable!("loop always returns before exhausting its range")
+        }
+
         /// Sets the value of [insight_type_config][crate::model::UpdateInsightTypeConfigRequest::insight_type_config].
         ///
         /// This is a **required** field for requests.
@@ -915,4 +1515,463 @@ pub mod recommender {
             &mut self.0.options
         }
     }
+
+    /// A builder that fans out [ListRecommendations][super::super::client::Recommender::list_recommendations]
+    /// across every `(location, recommender)` pair under a project and merges
+    /// the results into a single item stream.
+    ///
+    /// `parent` strings are constructed as
+    /// `projects/{project}/locations/{location}/recommenders/{recommender}`.
+    #[derive(Clone, Debug)]
+    pub struct AggregatedListRecommendations {
+        stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
+        project: std::string::String,
+        locations: Vec<std::string::String>,
+        recommenders: Vec<std::string::String>,
+        filter: std::string::String,
+        page_size: i32,
+        concurrency: usize,
+    }
+
+    impl AggregatedListRecommendations {
+        pub(crate) fn new(
+            stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
+        ) -> Self {
+            Self {
+                stub,
+                project: std::string::String::new(),
+                locations: Vec::new(),
+                recommenders: Vec::new(),
+                filter: std::string::String::new(),
+                page_size: 0,
+                concurrency: 4,
+            }
+        }
+
+        /// Sets the project to aggregate recommendations for.
+        pub fn set_project<T: Into<std::string::String>>(mut self, v: T) -> Self {
+            self.project = v.into();
+            self
+        }
+
+        /// Sets the locations to fan out across, e.g. `["us-central1", "global"]`.
+        pub fn set_locations<T, V>(mut self, v: T) -> Self
+        where
+            T: std::iter::IntoIterator<Item = V>,
+            V: Into<std::string::String>,
+        {
+            self.locations = v.into_iter().map(Into::into).collect();
+            self
+        }
+
+        /// Sets the recommender subtypes to fan out across, e.g.
+        /// `["google.compute.instance.MachineTypeRecommender"]`.
+        pub fn set_recommenders<T, V>(mut self, v: T) -> Self
+        where
+            T: std::iter::IntoIterator<Item = V>,
+            V: Into<std::string::String>,
+        {
+            self.recommenders = v.into_iter().map(Into::into).collect();
+            self
+        }
+
+        /// Sets the filter applied to every underlying `ListRecommendations` call.
+        pub fn set_filter<T: Into<std::string::String>>(mut self, v: T) -> Self {
+            self.filter = v.into();
+            self
+        }
+
+        /// Sets the page size used by every underlying `ListRecommendations` call.
+        pub fn set_page_size<T: Into<i32>>(mut self, v: T) -> Self {
+            self.page_size = v.into();
+            self
+        }
+
+        /// Sets the maximum number of `(location, recommender)` parents that are
+        /// listed concurrently. Defaults to `4`.
+        pub fn set_concurrency(mut self, v: usize) -> Self {
+            self.concurrency = v;
+            self
+        }
+
+        fn parents(&self) -> Vec<std::string::String> {
+            self.locations
+                .iter()
+                .flat_map(|location| {
+                    self.recommenders.iter().map(move |recommender| {
+                        format!(
+                            "projects/{}/locations/{}/recommenders/{}",
+                            self.project, location, recommender
+                        )
+                    })
+                })
+                .collect()
+        }
+
+        /// Drives the per-parent `ListRecommendations` item streams concurrently
+        /// (bounded by [set_concurrency][Self::set_concurrency]) and merges the
+        /// results, tagging each item with the `parent` it came from.
+        pub async fn items(
+            self,
+        ) -> impl futures::Stream<Item = Result<(std::string::String, crate::model::Recommendation)>>
+        {
+            use futures::stream::StreamExt;
+            use gax::paginator::ItemPaginator;
+            let stub = self.stub;
+            let filter = self.filter;
+            let page_size = self.page_size;
+            let concurrency = self.concurrency.max(1);
+            let parents = self.parents();
+            futures::stream::iter(parents)
+                .map(move |parent| {
+                    let stub = stub.clone();
+                    let filter = filter.clone();
+                    async move {
+                        let mut builder = ListRecommendations::new(stub).set_parent(parent.clone());
+                        if page_size > 0 {
+                            builder = builder.set_page_size(page_size);
+                        }
+                        if !filter.is_empty() {
+                            builder = builder.set_filter(filter);
+                        }
+                        let mut items = builder.items().await;
+                        let mut page = Vec::new();
+                        while let Some(item) = items.next().await {
+                            page.push(item.map(|r| (parent.clone(), r)));
+                        }
+                        page
+                    }
+                })
+                .buffer_unordered(concurrency)
+                .flat_map(|page| futures::stream::iter(page))
+        }
+    }
+
+    /// A builder that fans out [ListInsights][super::super::client::Recommender::list_insights]
+    /// across every `(location, insight_type)` pair under a project and merges
+    /// the results into a single item stream.
+    ///
+    /// `parent` strings are constructed as
+    /// `projects/{project}/locations/{location}/insightTypes/{insight_type}`.
+    #[derive(Clone, Debug)]
+    pub struct AggregatedListInsights {
+        stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
+        project: std::string::String,
+        locations: Vec<std::string::String>,
+        insight_types: Vec<std::string::String>,
+        filter: std::string::String,
+        page_size: i32,
+        concurrency: usize,
+    }
+
+    impl AggregatedListInsights {
+        pub(crate) fn new(
+            stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
+        ) -> Self {
+            Self {
+                stub,
+                project: std::string::String::new(),
+                locations: Vec::new(),
+                insight_types: Vec::new(),
+                filter: std::string::String::new(),
+                page_size: 0,
+                concurrency: 4,
+            }
+        }
+
+        /// Sets the project to aggregate insights for.
+        pub fn set_project<T: Into<std::string::String>>(mut self, v: T) -> Self {
+            self.project = v.into();
+            self
+        }
+
+        /// Sets the locations to fan out across, e.g. `["us-central1", "global"]`.
+        pub fn set_locations<T, V>(mut self, v: T) -> Self
+        where
+            T: std::iter::IntoIterator<Item = V>,
+            V: Into<std::string::String>,
+        {
+            self.locations = v.into_iter().map(Into::into).collect();
+            self
+        }
+
+        /// Sets the insight subtypes to fan out across, e.g.
+        /// `["google.iam.policy.Insight"]`.
+        pub fn set_insight_types<T, V>(mut self, v: T) -> Self
+        where
+            T: std::iter::IntoIterator<Item = V>,
+            V: Into<std::string::String>,
+        {
+            self.insight_types = v.into_iter().map(Into::into).collect();
+            self
+        }
+
+        /// Sets the filter applied to every underlying `ListInsights` call.
+        pub fn set_filter<T: Into<std::string::String>>(mut self, v: T) -> Self {
+            self.filter = v.into();
+            self
+        }
+
+        /// Sets the page size used by every underlying `ListInsights` call.
+        pub fn set_page_size<T: Into<i32>>(mut self, v: T) -> Self {
+            self.page_size = v.into();
+            self
+        }
+
+        /// Sets the maximum number of `(location, insight_type)` parents that are
+        /// listed concurrently. Defaults to `4`.
+        pub fn set_concurrency(mut self, v: usize) -> Self {
+            self.concurrency = v;
+            self
+        }
+
+        fn parents(&self) -> Vec<std::string::String> {
+            self.locations
+                .iter()
+                .flat_map(|location| {
+                    self.insight_types.iter().map(move |insight_type| {
+                        format!(
+                            "projects/{}/locations/{}/insightTypes/{}",
+                            self.project, location, insight_type
+                        )
+                    })
+                })
+                .collect()
+        }
+
+        /// Drives the per-parent `ListInsights` item streams concurrently
+        /// (bounded by [set_concurrency][Self::set_concurrency]) and merges the
+        /// results, tagging each item with the `parent` it came from.
+        pub async fn items(
+            self,
+        ) -> impl futures::Stream<Item = Result<(std::string::String, crate::model::Insight)>> {
+            use futures::stream::StreamExt;
+            use gax::paginator::ItemPaginator;
+            let stub = self.stub;
+            let filter = self.filter;
+            let page_size = self.page_size;
+            let concurrency = self.concurrency.max(1);
+            let parents = self.parents();
+            futures::stream::iter(parents)
+                .map(move |parent| {
+                    let stub = stub.clone();
+                    let filter = filter.clone();
+                    async move {
+                        let mut builder = ListInsights::new(stub).set_parent(parent.clone());
+                        if page_size > 0 {
+                            builder = builder.set_page_size(page_size);
+                        }
+                        if !filter.is_empty() {
+                            builder = builder.set_filter(filter);
+                        }
+                        let mut items = builder.items().await;
+                        let mut page = Vec::new();
+                        while let Some(item) = items.next().await {
+                            page.push(item.map(|r| (parent.clone(), r)));
+                        }
+                        page
+                    }
+                })
+                .buffer_unordered(concurrency)
+                .flat_map(|page| futures::stream::iter(page))
+        }
+    }
+
+    /// The lifecycle states a [Recommendation][crate::model::Recommendation]
+    /// can be in, as tracked by [RecommendationTransition].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum RecommendationState {
+        Active,
+        Claimed,
+        Succeeded,
+        Failed,
+        Dismissed,
+    }
+
+    /// The lifecycle states an [Insight][crate::model::Insight] can be in, as
+    /// tracked by [InsightTransition].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum InsightState {
+        Active,
+        Accepted,
+        Dismissed,
+    }
+
+    /// Returned by [RecommendationTransition] and [InsightTransition] when the
+    /// requested transition is not legal from the entity's current state,
+    /// instead of sending a request that the server would reject.
+    #[derive(Clone, Debug)]
+    pub struct IllegalTransition {
+        pub entity: &'static str,
+        pub from: std::string::String,
+        pub to: std::string::String,
+    }
+
+    impl std::fmt::Display for IllegalTransition {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "cannot transition {} from {} to {}",
+                self.entity, self.from, self.to
+            )
+        }
+    }
+
+    impl std::error::Error for IllegalTransition {}
+
+    /// A typed view over a single [Recommendation][crate::model::Recommendation]
+    /// that only exposes the lifecycle transitions legal from its current
+    /// state, layered over the `Mark*` builders in this module.
+    ///
+    /// A recommendation moves `ACTIVE` -> `CLAIMED` -> {`SUCCEEDED`, `FAILED`},
+    /// or `ACTIVE` -> `DISMISSED`. Attempting any other transition returns an
+    /// [IllegalTransition] error rather than calling the underlying RPC.
+    #[derive(Clone, Debug)]
+    pub struct RecommendationTransition {
+        stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
+        name: std::string::String,
+        etag: std::string::String,
+        state: RecommendationState,
+    }
+
+    impl RecommendationTransition {
+        /// Creates a transition handle for `recommendation`, which the caller
+        /// asserts is currently in `state` (e.g. as last observed via
+        /// `GetRecommendation` or `ListRecommendations`).
+        pub fn new(
+            stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
+            recommendation: &crate::model::Recommendation,
+            state: RecommendationState,
+        ) -> Self {
+            Self {
+                stub,
+                name: recommendation.name.clone(),
+                etag: recommendation.etag.clone(),
+                state,
+            }
+        }
+
+        fn check(&self, target: RecommendationState, allowed: RecommendationState) -> Result<()> {
+            if self.state == allowed {
+                return Ok(());
+            }
+            Err(gax::error::Error::other(IllegalTransition {
+                entity: "Recommendation",
+                from: format!("{:?}", self.state),
+                to: format!("{target:?}"),
+            }))
+        }
+
+        /// Moves the recommendation from `ACTIVE` to `CLAIMED`.
+        pub async fn to_claimed(
+            self,
+            state_metadata: std::collections::HashMap<std::string::String, std::string::String>,
+        ) -> Result<crate::model::Recommendation> {
+            self.check(RecommendationState::Claimed, RecommendationState::Active)?;
+            MarkRecommendationClaimed::new(self.stub)
+                .set_name(self.name)
+                .set_etag(self.etag)
+                .set_state_metadata(state_metadata)
+                .send()
+                .await
+        }
+
+        /// Moves the recommendation from `CLAIMED` to `SUCCEEDED`.
+        pub async fn to_succeeded(
+            self,
+            state_metadata: std::collections::HashMap<std::string::String, std::string::String>,
+        ) -> Result<crate::model::Recommendation> {
+            self.check(RecommendationState::Succeeded, RecommendationState::Claimed)?;
+            MarkRecommendationSucceeded::new(self.stub)
+                .set_name(self.name)
+                .set_etag(self.etag)
+                .set_state_metadata(state_metadata)
+                .send()
+                .await
+        }
+
+        /// Moves the recommendation from `CLAIMED` to `FAILED`.
+        pub async fn to_failed(
+            self,
+            state_metadata: std::collections::HashMap<std::string::String, std::string::String>,
+        ) -> Result<crate::model::Recommendation> {
+            self.check(RecommendationState::Failed, RecommendationState::Claimed)?;
+            MarkRecommendationFailed::new(self.stub)
+                .set_name(self.name)
+                .set_etag(self.etag)
+                .set_state_metadata(state_metadata)
+                .send()
+                .await
+        }
+
+        /// Moves the recommendation from `ACTIVE` to `DISMISSED`.
+        pub async fn to_dismissed(self) -> Result<crate::model::Recommendation> {
+            self.check(RecommendationState::Dismissed, RecommendationState::Active)?;
+            MarkRecommendationDismissed::new(self.stub)
+                .set_name(self.name)
+                .set_etag(self.etag)
+                .send()
+                .await
+        }
+    }
+
+    /// A typed view over a single [Insight][crate::model::Insight] that only
+    /// exposes the lifecycle transitions legal from its current state,
+    /// layered over the `Mark*` builders in this module.
+    ///
+    /// An insight moves `ACTIVE` -> `ACCEPTED`, which is terminal; insights
+    /// may also become `DISMISSED`, but only the server does so (there is no
+    /// corresponding RPC to call here). Attempting an illegal transition
+    /// returns an [IllegalTransition] error rather than calling the
+    /// underlying RPC.
+    #[derive(Clone, Debug)]
+    pub struct InsightTransition {
+        stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
+        name: std::string::String,
+        etag: std::string::String,
+        state: InsightState,
+    }
+
+    impl InsightTransition {
+        /// Creates a transition handle for `insight`, which the caller
+        /// asserts is currently in `state` (e.g. as last observed via
+        /// `GetInsight` or `ListInsights`).
+        pub fn new(
+            stub: std::sync::Arc<dyn super::super::stub::dynamic::Recommender>,
+            insight: &crate::model::Insight,
+            state: InsightState,
+        ) -> Self {
+            Self {
+                stub,
+                name: insight.name.clone(),
+                etag: insight.etag.clone(),
+                state,
+            }
+        }
+
+        fn check(&self, target: InsightState, allowed: InsightState) -> Result<()> {
+            if self.state == allowed {
+                return Ok(());
+            }
+            Err(gax::error::Error::other(IllegalTransition {
+                entity: "Insight",
+                from: format!("{:?}", self.state),
+                to: format!("{target:?}"),
+            }))
+        }
+
+        /// Moves the insight from `ACTIVE` to `ACCEPTED`.
+        pub async fn to_accepted(
+            self,
+            state_metadata: std::collections::HashMap<std::string::String, std::string::String>,
+        ) -> Result<crate::model::Insight> {
+            self.check(InsightState::Accepted, InsightState::Active)?;
+            MarkInsightAccepted::new(self.stub)
+                .set_name(self.name)
+                .set_etag(self.etag)
+                .set_state_metadata(state_metadata)
+                .send()
+                .await
+        }
+    }
 }
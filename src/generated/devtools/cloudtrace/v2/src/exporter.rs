@@ -0,0 +1,245 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An [opentelemetry::trace::SpanExporter] backed by [crate::client::TraceService].
+
+use opentelemetry::trace::{SpanId, TraceError, TraceId};
+use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+
+/// Cloud Trace rejects spans with more than this many attributes per span,
+/// dropping the remainder and recording the count in
+/// [Attributes::dropped_attributes_count][crate::model::Attributes::dropped_attributes_count].
+const MAX_ATTRIBUTES: usize = 128;
+
+/// Cloud Trace rejects spans with more than this many links per span,
+/// dropping the remainder and recording the count in
+/// [Links::dropped_links_count][crate::model::Links::dropped_links_count].
+const MAX_LINKS: usize = 32;
+
+/// Cloud Trace rejects spans with more than this many time events per span,
+/// dropping the remainder and recording the count in
+/// [TimeEvents::dropped_annotations_count][crate::model::TimeEvents::dropped_annotations_count].
+const MAX_TIME_EVENTS: usize = 128;
+
+/// An [opentelemetry::trace::SpanExporter] that writes spans to Cloud Trace
+/// via [TraceService::batch_write_spans][crate::client::TraceService::batch_write_spans].
+///
+/// Each OTel [SpanData] is translated into a v2 [Span][crate::model::Span]
+/// and the whole batch is sent as a single `BatchWriteSpans` call.
+#[derive(Clone, Debug)]
+pub struct CloudTraceExporter {
+    client: crate::client::TraceService,
+    project_id: String,
+}
+
+impl CloudTraceExporter {
+    /// Creates a new exporter that writes spans under `projects/{project_id}`.
+    pub fn new(client: crate::client::TraceService, project_id: impl Into<String>) -> Self {
+        Self {
+            client,
+            project_id: project_id.into(),
+        }
+    }
+
+    fn parent(&self) -> String {
+        format!("projects/{}", self.project_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl SpanExporter for CloudTraceExporter {
+    async fn export(&mut self, batch: Vec<SpanData>) -> ExportResult {
+        let spans = batch
+            .into_iter()
+            .map(|span| convert_span(&self.project_id, span))
+            .collect();
+        self.client
+            .batch_write_spans(self.parent())
+            .set_spans(spans)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| TraceError::from(e.to_string()))
+    }
+}
+
+/// Renders a 16-byte OTel [TraceId] as the lowercase hex string Cloud Trace
+/// expects.
+fn trace_id_hex(id: TraceId) -> String {
+    format!("{id:032x}")
+}
+
+/// Renders an 8-byte OTel [SpanId] as the lowercase hex string Cloud Trace
+/// expects.
+fn span_id_hex(id: SpanId) -> String {
+    format!("{id:016x}")
+}
+
+/// Renders a span's parent [SpanId], the way Cloud Trace expects it: an
+/// empty string marks a root span, rather than the hex encoding of
+/// [SpanId::INVALID].
+fn parent_span_id_hex(id: SpanId) -> String {
+    if id == SpanId::INVALID {
+        String::new()
+    } else {
+        span_id_hex(id)
+    }
+}
+
+/// Builds the `projects/{project}/traces/{trace}/spans/{span}` resource name
+/// for a span.
+fn span_name(project_id: &str, trace_id: TraceId, span_id: SpanId) -> String {
+    format!(
+        "projects/{project_id}/traces/{}/spans/{}",
+        trace_id_hex(trace_id),
+        span_id_hex(span_id)
+    )
+}
+
+fn to_timestamp(t: std::time::SystemTime) -> wkt::Timestamp {
+    wkt::Timestamp::try_from(t).unwrap_or_default()
+}
+
+fn to_truncatable(value: impl Into<String>) -> crate::model::TruncatableString {
+    crate::model::TruncatableString {
+        value: value.into(),
+        ..Default::default()
+    }
+}
+
+fn convert_attributes(
+    attributes: impl IntoIterator<Item = opentelemetry::KeyValue>,
+) -> crate::model::Attributes {
+    let mut attribute_map = std::collections::HashMap::new();
+    let mut dropped = 0i32;
+    for kv in attributes {
+        if attribute_map.len() >= MAX_ATTRIBUTES {
+            dropped += 1;
+            continue;
+        }
+        attribute_map.insert(kv.key.to_string(), convert_attribute_value(kv.value));
+    }
+    crate::model::Attributes {
+        attribute_map,
+        dropped_attributes_count: dropped,
+        ..Default::default()
+    }
+}
+
+fn convert_attribute_value(value: opentelemetry::Value) -> crate::model::AttributeValue {
+    use crate::model::attribute_value::Value;
+    let value = match value {
+        opentelemetry::Value::Bool(v) => Value::BoolValue(v),
+        opentelemetry::Value::I64(v) => Value::IntValue(v),
+        other => Value::StringValue(to_truncatable(other.to_string())),
+    };
+    crate::model::AttributeValue {
+        value: Some(value),
+        ..Default::default()
+    }
+}
+
+fn convert_status(status: &opentelemetry::trace::Status) -> crate::model::Status {
+    match status {
+        opentelemetry::trace::Status::Unset => crate::model::Status::default(),
+        opentelemetry::trace::Status::Ok => crate::model::Status {
+            code: 0,
+            ..Default::default()
+        },
+        opentelemetry::trace::Status::Error { description } => crate::model::Status {
+            code: 2, // google.rpc.Code.UNKNOWN
+            message: description.to_string(),
+            ..Default::default()
+        },
+    }
+}
+
+fn convert_time_events(events: opentelemetry_sdk::trace::SpanEvents) -> crate::model::TimeEvents {
+    let total = events.events.len();
+    let time_event = events
+        .events
+        .into_iter()
+        .take(MAX_TIME_EVENTS)
+        .map(|event| crate::model::TimeEvent {
+            time: Some(to_timestamp(event.timestamp)),
+            value: Some(crate::model::time_event::Value::Annotation(
+                crate::model::time_event::Annotation {
+                    description: Some(to_truncatable(event.name)),
+                    attributes: Some(convert_attributes(event.attributes)),
+                    ..Default::default()
+                },
+            )),
+            ..Default::default()
+        })
+        .collect();
+    crate::model::TimeEvents {
+        time_event,
+        dropped_annotations_count: total.saturating_sub(MAX_TIME_EVENTS) as i32,
+        ..Default::default()
+    }
+}
+
+fn convert_links(links: opentelemetry_sdk::trace::SpanLinks) -> crate::model::Links {
+    let total = links.links.len();
+    let link = links
+        .links
+        .into_iter()
+        .take(MAX_LINKS)
+        .map(|link| crate::model::Link {
+            trace_id: trace_id_hex(link.span_context.trace_id()),
+            span_id: span_id_hex(link.span_context.span_id()),
+            attributes: Some(convert_attributes(link.attributes)),
+            ..Default::default()
+        })
+        .collect();
+    crate::model::Links {
+        link,
+        dropped_links_count: total.saturating_sub(MAX_LINKS) as i32,
+        ..Default::default()
+    }
+}
+
+fn convert_span(project_id: &str, span: SpanData) -> crate::model::Span {
+    let trace_id = span.span_context.trace_id();
+    let span_id = span.span_context.span_id();
+    crate::model::Span {
+        name: span_name(project_id, trace_id, span_id),
+        span_id: span_id_hex(span_id),
+        parent_span_id: parent_span_id_hex(span.parent_span_id),
+        display_name: Some(to_truncatable(span.name.to_string())),
+        start_time: Some(to_timestamp(span.start_time)),
+        end_time: Some(to_timestamp(span.end_time)),
+        attributes: Some(convert_attributes(span.attributes)),
+        time_events: Some(convert_time_events(span.events)),
+        links: Some(convert_links(span.links)),
+        status: Some(convert_status(&span.status)),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_span_has_empty_parent_span_id() {
+        assert_eq!(parent_span_id_hex(SpanId::INVALID), "");
+    }
+
+    #[test]
+    fn child_span_has_hex_encoded_parent_span_id() {
+        let id = SpanId::from_bytes([0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(parent_span_id_hex(id), "0000000000000001");
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fan-out polling across many outstanding long-running operations.
+//!
+//! Admin workflows frequently launch dozens of backup-schedule or restore
+//! operations at once and then have to wait for all of them. [poll_all]
+//! resolves the [PollingPolicy][gax::polling_policy::PollingPolicy] and
+//! [PollingBackoffPolicy][gax::polling_backoff_policy::PollingBackoffPolicy]
+//! once and shares them (and the `loop_start` they measure from) across
+//! every operation. Each operation still runs its own poll loop with its own
+//! attempt count, so the aggregate poll rate grows with the number of
+//! operations in flight; sharing the policies does not throttle it.
+
+use crate::poller::decode_result;
+use crate::traits::DatabaseAdmin;
+use futures::stream::FuturesUnordered;
+use gax::loop_state::LoopState;
+use std::time::Instant;
+
+/// Polls every operation in `names` concurrently to completion, decoding
+/// each as `T`, and yields `(name, result)` as each operation finishes.
+///
+/// Every poller shares one [PollingPolicy][gax::polling_policy::PollingPolicy]
+/// and [PollingBackoffPolicy][gax::polling_backoff_policy::PollingBackoffPolicy],
+/// resolved once via `admin.get_polling_policy(&options)` /
+/// `admin.get_polling_backoff_policy(&options)`. Each operation polls on its
+/// own schedule, so the combined poll rate grows with `names.len()`. A
+/// failure polling one operation (a
+/// permanent error, or a transient one the shared policy gives up on) is
+/// surfaced only for that operation; every other operation keeps polling
+/// independently.
+pub fn poll_all<A, T>(
+    admin: A,
+    names: Vec<String>,
+    options: gax::options::RequestOptions,
+) -> FuturesUnordered<impl std::future::Future<Output = (String, crate::Result<T>)>>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+    T: Default + serde::de::DeserializeOwned + Send + 'static,
+{
+    let polling_policy = admin.get_polling_policy(&options);
+    let backoff_policy = admin.get_polling_backoff_policy(&options);
+    let loop_start = Instant::now();
+
+    names
+        .into_iter()
+        .map(move |name| {
+            let admin = admin.clone();
+            let options = options.clone();
+            let polling_policy = polling_policy.clone();
+            let backoff_policy = backoff_policy.clone();
+            async move {
+                let mut attempt = 0u32;
+                loop {
+                    attempt += 1;
+                    let req =
+                        longrunning::model::GetOperationRequest::new().set_name(name.clone());
+                    match admin.get_operation(req, options.clone()).await {
+                        Ok(op) if op.done => return (name, decode_result::<T>(&op)),
+                        Ok(_) => {}
+                        Err(error) => {
+                            let decision = polling_policy.on_error(loop_start, attempt, error);
+                            gax::polling_trace::trace_decision(&name, attempt, &decision);
+                            match decision {
+                                LoopState::Continue(_) => {}
+                                LoopState::Exhausted(error) | LoopState::Permanent(error) => {
+                                    return (name, Err(error));
+                                }
+                            }
+                        }
+                    }
+                    let wait = backoff_policy.wait_period(loop_start, attempt);
+                    gax::polling_trace::trace_attempt(&name, attempt, wait);
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,303 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [DatabaseAdmin][crate::traits::DatabaseAdmin] decorator that forwards
+//! every method to an inner client by default.
+//!
+//! [ForwardingService] is a base for middleware: request logging, tenant
+//! header injection, operation-name rewriting, routing to a different
+//! backend, and so on. Every method already has a body that forwards to
+//! `self.inner`, so a [ForwardingService] can stand in wherever a complete
+//! [DatabaseAdmin][crate::traits::DatabaseAdmin] is needed. It has no hook
+//! for replacing a single method: a type that changes some methods
+//! implements [DatabaseAdmin][crate::traits::DatabaseAdmin] itself and
+//! writes a forwarding body (e.g. through a held [ForwardingService] or
+//! [ForwardingService::inner]) for every other method it uses. Any method
+//! it leaves out falls back to the trait's own `"unimplemented"` default.
+
+/// Implements [DatabaseAdmin][crate::traits::DatabaseAdmin] by forwarding
+/// every method to `S`. See the [module][self] docs.
+#[derive(Clone, Debug)]
+pub struct ForwardingService<S>
+where
+    S: crate::traits::DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    inner: S,
+}
+
+impl<S> ForwardingService<S>
+where
+    S: crate::traits::DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    /// Wraps `inner`, forwarding every [DatabaseAdmin] method to it.
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S> crate::traits::DatabaseAdmin for ForwardingService<S>
+where
+    S: crate::traits::DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    async fn list_databases(
+        &self,
+        req: crate::model::ListDatabasesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabasesResponse> {
+        self.inner.list_databases(req, options).await
+    }
+
+    async fn create_database(
+        &self,
+        req: crate::model::CreateDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.create_database(req, options).await
+    }
+
+    async fn get_database(
+        &self,
+        req: crate::model::GetDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Database> {
+        self.inner.get_database(req, options).await
+    }
+
+    async fn update_database(
+        &self,
+        req: crate::model::UpdateDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.update_database(req, options).await
+    }
+
+    async fn update_database_ddl(
+        &self,
+        req: crate::model::UpdateDatabaseDdlRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.update_database_ddl(req, options).await
+    }
+
+    async fn drop_database(
+        &self,
+        req: crate::model::DropDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.drop_database(req, options).await
+    }
+
+    async fn get_database_ddl(
+        &self,
+        req: crate::model::GetDatabaseDdlRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::GetDatabaseDdlResponse> {
+        self.inner.get_database_ddl(req, options).await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::Policy> {
+        self.inner.set_iam_policy(req, options).await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::Policy> {
+        self.inner.get_iam_policy(req, options).await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::TestIamPermissionsResponse> {
+        self.inner.test_iam_permissions(req, options).await
+    }
+
+    async fn create_backup(
+        &self,
+        req: crate::model::CreateBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.create_backup(req, options).await
+    }
+
+    async fn copy_backup(
+        &self,
+        req: crate::model::CopyBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.copy_backup(req, options).await
+    }
+
+    async fn get_backup(
+        &self,
+        req: crate::model::GetBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Backup> {
+        self.inner.get_backup(req, options).await
+    }
+
+    async fn update_backup(
+        &self,
+        req: crate::model::UpdateBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Backup> {
+        self.inner.update_backup(req, options).await
+    }
+
+    async fn delete_backup(
+        &self,
+        req: crate::model::DeleteBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.delete_backup(req, options).await
+    }
+
+    async fn list_backups(
+        &self,
+        req: crate::model::ListBackupsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupsResponse> {
+        self.inner.list_backups(req, options).await
+    }
+
+    async fn restore_database(
+        &self,
+        req: crate::model::RestoreDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.restore_database(req, options).await
+    }
+
+    async fn list_database_operations(
+        &self,
+        req: crate::model::ListDatabaseOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabaseOperationsResponse> {
+        self.inner.list_database_operations(req, options).await
+    }
+
+    async fn list_backup_operations(
+        &self,
+        req: crate::model::ListBackupOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupOperationsResponse> {
+        self.inner.list_backup_operations(req, options).await
+    }
+
+    async fn list_database_roles(
+        &self,
+        req: crate::model::ListDatabaseRolesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabaseRolesResponse> {
+        self.inner.list_database_roles(req, options).await
+    }
+
+    async fn create_backup_schedule(
+        &self,
+        req: crate::model::CreateBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.inner.create_backup_schedule(req, options).await
+    }
+
+    async fn get_backup_schedule(
+        &self,
+        req: crate::model::GetBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.inner.get_backup_schedule(req, options).await
+    }
+
+    async fn update_backup_schedule(
+        &self,
+        req: crate::model::UpdateBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.inner.update_backup_schedule(req, options).await
+    }
+
+    async fn delete_backup_schedule(
+        &self,
+        req: crate::model::DeleteBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.delete_backup_schedule(req, options).await
+    }
+
+    async fn list_backup_schedules(
+        &self,
+        req: crate::model::ListBackupSchedulesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupSchedulesResponse> {
+        self.inner.list_backup_schedules(req, options).await
+    }
+
+    async fn list_operations(
+        &self,
+        req: longrunning::model::ListOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::ListOperationsResponse> {
+        self.inner.list_operations(req, options).await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.get_operation(req, options).await
+    }
+
+    async fn delete_operation(
+        &self,
+        req: longrunning::model::DeleteOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.delete_operation(req, options).await
+    }
+
+    async fn cancel_operation(
+        &self,
+        req: longrunning::model::CancelOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.cancel_operation(req, options).await
+    }
+
+    fn get_polling_policy(
+        &self,
+        options: &gax::options::RequestOptions,
+    ) -> std::sync::Arc<dyn gax::polling_policy::PollingPolicy> {
+        self.inner.get_polling_policy(options)
+    }
+
+    fn get_polling_backoff_policy(
+        &self,
+        options: &gax::options::RequestOptions,
+    ) -> std::sync::Arc<dyn gax::polling_backoff_policy::PollingBackoffPolicy> {
+        self.inner.get_polling_backoff_policy(options)
+    }
+}
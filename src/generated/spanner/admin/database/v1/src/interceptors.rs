@@ -0,0 +1,346 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [DatabaseAdmin][crate::traits::DatabaseAdmin] decorator that runs a
+//! [gax::interceptor::InterceptorChain] before every call.
+//!
+//! This lets callers attach per-call `x-goog-request-params` resource
+//! routing headers, OpenTelemetry trace context, or a dynamic API key
+//! uniformly, without wrapping each of the ~25 methods by hand. The chain is
+//! installed once, at client-build time, via [Intercepted::new_with_chain].
+//!
+//! There is no transport in this tree for the resulting metadata to attach
+//! to, so each method below runs the chain against a fresh [Metadata] and
+//! traces the result before forwarding; a real transport would instead send
+//! that metadata with the request.
+
+use gax::interceptor::{InterceptorChain, Metadata};
+
+/// Implements a [DatabaseAdmin](crate::traits::DatabaseAdmin) decorator that
+/// runs an [InterceptorChain] before every call.
+#[derive(Clone, Debug)]
+pub struct Intercepted<T>
+where
+    T: crate::traits::DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    chain: InterceptorChain,
+}
+
+impl<T> Intercepted<T>
+where
+    T: crate::traits::DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    /// Creates a new decorator with an empty (no-op) chain.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            chain: InterceptorChain::new(),
+        }
+    }
+
+    /// Creates a new decorator that runs `chain` before every call.
+    pub fn new_with_chain(inner: T, chain: InterceptorChain) -> Self {
+        Self { inner, chain }
+    }
+
+    fn before(&self, rpc_name: &str, options: &gax::options::RequestOptions) -> Metadata {
+        let mut metadata = Metadata::new();
+        self.chain.before(rpc_name, &mut metadata, options);
+        tracing::trace!(?metadata, rpc = rpc_name, "DatabaseAdmin interceptor chain");
+        metadata
+    }
+}
+
+impl<T> crate::traits::DatabaseAdmin for Intercepted<T>
+where
+    T: crate::traits::DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    async fn list_databases(
+        &self,
+        req: crate::model::ListDatabasesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabasesResponse> {
+        self.before("DatabaseAdmin.ListDatabases", &options);
+        self.inner.list_databases(req, options).await
+    }
+
+    async fn create_database(
+        &self,
+        req: crate::model::CreateDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.before("DatabaseAdmin.CreateDatabase", &options);
+        self.inner.create_database(req, options).await
+    }
+
+    async fn get_database(
+        &self,
+        req: crate::model::GetDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Database> {
+        self.before("DatabaseAdmin.GetDatabase", &options);
+        self.inner.get_database(req, options).await
+    }
+
+    async fn update_database(
+        &self,
+        req: crate::model::UpdateDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.before("DatabaseAdmin.UpdateDatabase", &options);
+        self.inner.update_database(req, options).await
+    }
+
+    async fn update_database_ddl(
+        &self,
+        req: crate::model::UpdateDatabaseDdlRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.before("DatabaseAdmin.UpdateDatabaseDdl", &options);
+        self.inner.update_database_ddl(req, options).await
+    }
+
+    async fn drop_database(
+        &self,
+        req: crate::model::DropDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.before("DatabaseAdmin.DropDatabase", &options);
+        self.inner.drop_database(req, options).await
+    }
+
+    async fn get_database_ddl(
+        &self,
+        req: crate::model::GetDatabaseDdlRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::GetDatabaseDdlResponse> {
+        self.before("DatabaseAdmin.GetDatabaseDdl", &options);
+        self.inner.get_database_ddl(req, options).await
+    }
+
+    /// Runs the chain first: this is the method most likely to carry a
+    /// caller-supplied `x-goog-request-params` or IAM credential override.
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::Policy> {
+        self.before("DatabaseAdmin.SetIamPolicy", &options);
+        self.inner.set_iam_policy(req, options).await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::Policy> {
+        self.before("DatabaseAdmin.GetIamPolicy", &options);
+        self.inner.get_iam_policy(req, options).await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::TestIamPermissionsResponse> {
+        self.before("DatabaseAdmin.TestIamPermissions", &options);
+        self.inner.test_iam_permissions(req, options).await
+    }
+
+    async fn create_backup(
+        &self,
+        req: crate::model::CreateBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.before("DatabaseAdmin.CreateBackup", &options);
+        self.inner.create_backup(req, options).await
+    }
+
+    async fn copy_backup(
+        &self,
+        req: crate::model::CopyBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.before("DatabaseAdmin.CopyBackup", &options);
+        self.inner.copy_backup(req, options).await
+    }
+
+    async fn get_backup(
+        &self,
+        req: crate::model::GetBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Backup> {
+        self.before("DatabaseAdmin.GetBackup", &options);
+        self.inner.get_backup(req, options).await
+    }
+
+    async fn update_backup(
+        &self,
+        req: crate::model::UpdateBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Backup> {
+        self.before("DatabaseAdmin.UpdateBackup", &options);
+        self.inner.update_backup(req, options).await
+    }
+
+    async fn delete_backup(
+        &self,
+        req: crate::model::DeleteBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.before("DatabaseAdmin.DeleteBackup", &options);
+        self.inner.delete_backup(req, options).await
+    }
+
+    async fn list_backups(
+        &self,
+        req: crate::model::ListBackupsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupsResponse> {
+        self.before("DatabaseAdmin.ListBackups", &options);
+        self.inner.list_backups(req, options).await
+    }
+
+    async fn restore_database(
+        &self,
+        req: crate::model::RestoreDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.before("DatabaseAdmin.RestoreDatabase", &options);
+        self.inner.restore_database(req, options).await
+    }
+
+    async fn list_database_operations(
+        &self,
+        req: crate::model::ListDatabaseOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabaseOperationsResponse> {
+        self.before("DatabaseAdmin.ListDatabaseOperations", &options);
+        self.inner.list_database_operations(req, options).await
+    }
+
+    async fn list_backup_operations(
+        &self,
+        req: crate::model::ListBackupOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupOperationsResponse> {
+        self.before("DatabaseAdmin.ListBackupOperations", &options);
+        self.inner.list_backup_operations(req, options).await
+    }
+
+    async fn list_database_roles(
+        &self,
+        req: crate::model::ListDatabaseRolesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabaseRolesResponse> {
+        self.before("DatabaseAdmin.ListDatabaseRoles", &options);
+        self.inner.list_database_roles(req, options).await
+    }
+
+    async fn create_backup_schedule(
+        &self,
+        req: crate::model::CreateBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.before("DatabaseAdmin.CreateBackupSchedule", &options);
+        self.inner.create_backup_schedule(req, options).await
+    }
+
+    async fn get_backup_schedule(
+        &self,
+        req: crate::model::GetBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.before("DatabaseAdmin.GetBackupSchedule", &options);
+        self.inner.get_backup_schedule(req, options).await
+    }
+
+    async fn update_backup_schedule(
+        &self,
+        req: crate::model::UpdateBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.before("DatabaseAdmin.UpdateBackupSchedule", &options);
+        self.inner.update_backup_schedule(req, options).await
+    }
+
+    async fn delete_backup_schedule(
+        &self,
+        req: crate::model::DeleteBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.before("DatabaseAdmin.DeleteBackupSchedule", &options);
+        self.inner.delete_backup_schedule(req, options).await
+    }
+
+    async fn list_backup_schedules(
+        &self,
+        req: crate::model::ListBackupSchedulesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupSchedulesResponse> {
+        self.before("DatabaseAdmin.ListBackupSchedules", &options);
+        self.inner.list_backup_schedules(req, options).await
+    }
+
+    async fn list_operations(
+        &self,
+        req: longrunning::model::ListOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::ListOperationsResponse> {
+        self.before("DatabaseAdmin.ListOperations", &options);
+        self.inner.list_operations(req, options).await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.before("DatabaseAdmin.GetOperation", &options);
+        self.inner.get_operation(req, options).await
+    }
+
+    async fn delete_operation(
+        &self,
+        req: longrunning::model::DeleteOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.before("DatabaseAdmin.DeleteOperation", &options);
+        self.inner.delete_operation(req, options).await
+    }
+
+    async fn cancel_operation(
+        &self,
+        req: longrunning::model::CancelOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.before("DatabaseAdmin.CancelOperation", &options);
+        self.inner.cancel_operation(req, options).await
+    }
+
+    fn get_polling_policy(
+        &self,
+        options: &gax::options::RequestOptions,
+    ) -> std::sync::Arc<dyn gax::polling_policy::PollingPolicy> {
+        self.inner.get_polling_policy(options)
+    }
+
+    fn get_polling_backoff_policy(
+        &self,
+        options: &gax::options::RequestOptions,
+    ) -> std::sync::Arc<dyn gax::polling_backoff_policy::PollingBackoffPolicy> {
+        self.inner.get_polling_backoff_policy(options)
+    }
+}
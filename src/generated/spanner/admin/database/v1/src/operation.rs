@@ -0,0 +1,259 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A typed, self-polling handle for a [longrunning::model::Operation].
+//!
+//! [DatabaseAdmin][crate::traits::DatabaseAdmin] already exposes
+//! `get_operation` and the two polling-policy accessors, but callers who
+//! start a long-running RPC still have to hand-write the poll loop. [Operation]
+//! wraps the bare [longrunning::model::Operation] returned by RPCs like
+//! `create_database` together with the client that started it, and drives
+//! the same poll loop [poller][crate::poller] does, minus the
+//! per-RPC progress decoding: `poll_until_done` (or simply `.await`ing the
+//! handle, via [IntoFuture]) blocks until the operation finishes and
+//! decodes its response into `T`; `try_poll_once` does a single
+//! non-blocking poll for callers who want to drive the loop themselves.
+//!
+//! Use [poller][crate::poller]'s `*_and_poll` methods instead if you also
+//! want the intermediate progress updates.
+
+use crate::poller::decode_result;
+use crate::traits::DatabaseAdmin;
+use futures::Stream;
+use gax::loop_state::LoopState;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::time::Instant;
+
+/// A handle to a [longrunning::model::Operation] that knows how to poll
+/// itself to completion and decode its response as `T`.
+///
+/// `T` should be the response type documented on the metadata of the RPC
+/// that returned the wrapped operation (for example
+/// [crate::model::Database] for `create_database`).
+pub struct Operation<A, T>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+    T: Default + serde::de::DeserializeOwned + Send + 'static,
+{
+    admin: A,
+    operation: longrunning::model::Operation,
+    options: gax::options::RequestOptions,
+    _response: PhantomData<T>,
+}
+
+impl<A, T> Operation<A, T>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+    T: Default + serde::de::DeserializeOwned + Send + 'static,
+{
+    /// Wraps `operation`, polling with the default [RequestOptions][gax::options::RequestOptions].
+    pub fn new(admin: A, operation: longrunning::model::Operation) -> Self {
+        Self::new_with_options(admin, operation, gax::options::RequestOptions::default())
+    }
+
+    /// Wraps `operation`, polling with `options` (and, through it, whatever
+    /// [PollingPolicy][gax::polling_policy::PollingPolicy] and
+    /// [PollingBackoffPolicy][gax::polling_backoff_policy::PollingBackoffPolicy]
+    /// it selects).
+    pub fn new_with_options(
+        admin: A,
+        operation: longrunning::model::Operation,
+        options: gax::options::RequestOptions,
+    ) -> Self {
+        Self {
+            admin,
+            operation,
+            options,
+            _response: PhantomData,
+        }
+    }
+
+    /// The operation's resource name.
+    pub fn name(&self) -> &str {
+        &self.operation.name
+    }
+
+    /// Whether the most recently observed state of the operation is done.
+    /// This reflects the last poll, not necessarily the operation's current
+    /// state: call [Self::try_poll_once] or [Self::poll_until_done] to
+    /// refresh it.
+    pub fn done(&self) -> bool {
+        self.operation.done
+    }
+
+    /// Polls the operation exactly once, without waiting or retrying.
+    ///
+    /// Returns `Ok(None)` if the operation is still pending, or
+    /// `Ok(Some(response))` once it has finished. Unlike
+    /// [Self::poll_until_done], a transient error from `get_operation` is
+    /// simply returned to the caller rather than retried.
+    pub async fn try_poll_once(&mut self) -> crate::Result<Option<T>> {
+        if self.operation.done {
+            return decode_result::<T>(&self.operation).map(Some);
+        }
+        let req = longrunning::model::GetOperationRequest::new().set_name(self.operation.name.clone());
+        self.operation = self.admin.get_operation(req, self.options.clone()).await?;
+        if self.operation.done {
+            decode_result::<T>(&self.operation).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Polls until the operation finishes, honoring the
+    /// [PollingPolicy][gax::polling_policy::PollingPolicy] and
+    /// [PollingBackoffPolicy][gax::polling_backoff_policy::PollingBackoffPolicy]
+    /// [DatabaseAdmin::get_polling_policy] and
+    /// [DatabaseAdmin::get_polling_backoff_policy] return for `self.options`,
+    /// then decodes the response as `T`.
+    pub async fn poll_until_done(mut self) -> crate::Result<T> {
+        if self.operation.done {
+            return decode_result::<T>(&self.operation);
+        }
+        let polling_policy = self.admin.get_polling_policy(&self.options);
+        let backoff_policy = self.admin.get_polling_backoff_policy(&self.options);
+        let loop_start = Instant::now();
+        let mut attempt = 0u32;
+        loop {
+            attempt += 1;
+            let wait = backoff_policy.wait_period(loop_start, attempt);
+            gax::polling_trace::trace_attempt(&self.operation.name, attempt, wait);
+            tokio::time::sleep(wait).await;
+            let req =
+                longrunning::model::GetOperationRequest::new().set_name(self.operation.name.clone());
+            match self.admin.get_operation(req, self.options.clone()).await {
+                Ok(op) => {
+                    self.operation = op;
+                    if self.operation.done {
+                        return decode_result::<T>(&self.operation);
+                    }
+                }
+                Err(error) => {
+                    let decision = polling_policy.on_error(loop_start, attempt, error);
+                    gax::polling_trace::trace_decision(&self.operation.name, attempt, &decision);
+                    match decision {
+                        LoopState::Continue(_) => {}
+                        LoopState::Exhausted(error) | LoopState::Permanent(error) => {
+                            return Err(error)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<A, T> IntoFuture for Operation<A, T>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+    T: Default + serde::de::DeserializeOwned + Send + 'static,
+{
+    type Output = crate::Result<T>;
+    type IntoFuture = Pin<Box<dyn std::future::Future<Output = Self::Output> + Send>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.poll_until_done())
+    }
+}
+
+/// An update yielded while streaming [Operation::poll_stream].
+pub enum OperationEvent<M, T> {
+    /// The operation is still in progress. Carries its metadata, decoded as
+    /// `M`, if the operation has any and it decoded successfully.
+    Progress(Option<M>),
+    /// The operation has finished, successfully or not. This is always the
+    /// last item the stream yields.
+    Done(crate::Result<T>),
+}
+
+impl<A, T> Operation<A, T>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+    T: Default + serde::de::DeserializeOwned + Send + 'static,
+{
+    /// Polls the operation to completion like [Self::poll_until_done], but
+    /// yields an [OperationEvent::Progress] decoding the operation's
+    /// service-specific metadata (e.g. `create_time`, `progress_percent`)
+    /// after every poll, rather than surfacing only the terminal result.
+    ///
+    /// This is the generic counterpart of [poller][crate::poller]'s
+    /// `*_and_poll` methods: those already know which metadata type to
+    /// decode for a handful of specific RPCs, while this lets any caller
+    /// holding an [Operation] supply `M` themselves.
+    pub fn poll_stream<M>(self) -> impl Stream<Item = OperationEvent<M, T>>
+    where
+        M: Default + serde::de::DeserializeOwned + Send + 'static,
+    {
+        fn decode_metadata<M>(operation: &longrunning::model::Operation) -> Option<M>
+        where
+            M: Default + serde::de::DeserializeOwned,
+        {
+            operation.metadata.as_ref()?.to_msg::<M>().ok()
+        }
+
+        let polling_policy = self.admin.get_polling_policy(&self.options);
+        let backoff_policy = self.admin.get_polling_backoff_policy(&self.options);
+        let loop_start = Instant::now();
+
+        futures::stream::unfold(Some((self, 0u32)), move |state| {
+            let polling_policy = polling_policy.clone();
+            let backoff_policy = backoff_policy.clone();
+            async move {
+                let (mut this, mut attempt) = state?;
+                if this.operation.done {
+                    let result = decode_result::<T>(&this.operation);
+                    return Some((OperationEvent::Done(result), None));
+                }
+                loop {
+                    attempt += 1;
+                    let wait = backoff_policy.wait_period(loop_start, attempt);
+                    gax::polling_trace::trace_attempt(&this.operation.name, attempt, wait);
+                    tokio::time::sleep(wait).await;
+                    let req = longrunning::model::GetOperationRequest::new()
+                        .set_name(this.operation.name.clone());
+                    match this.admin.get_operation(req, this.options.clone()).await {
+                        Ok(op) => {
+                            this.operation = op;
+                            if this.operation.done {
+                                let result = decode_result::<T>(&this.operation);
+                                return Some((OperationEvent::Done(result), None));
+                            }
+                            let metadata = decode_metadata::<M>(&this.operation);
+                            return Some((
+                                OperationEvent::Progress(metadata),
+                                Some((this, attempt)),
+                            ));
+                        }
+                        Err(error) => {
+                            let decision = polling_policy.on_error(loop_start, attempt, error);
+                            gax::polling_trace::trace_decision(
+                                &this.operation.name,
+                                attempt,
+                                &decision,
+                            );
+                            match decision {
+                                LoopState::Continue(_) => continue,
+                                LoopState::Exhausted(error) | LoopState::Permanent(error) => {
+                                    return Some((OperationEvent::Done(Err(error)), None));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
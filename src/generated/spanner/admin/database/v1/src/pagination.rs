@@ -0,0 +1,211 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Auto-paginating streams for the `list_*` RPCs on
+//! [DatabaseAdmin][crate::traits::DatabaseAdmin].
+//!
+//! Each `list_*` RPC returns a response carrying a `next_page_token`, and
+//! today callers must hand-roll the token-follow loop themselves. This
+//! module wires those responses into [gax::paginator] — the same machinery
+//! other generated clients use for their request builders' `paginator()` and
+//! `items()` methods — so callers can instead do:
+//!
+//! ```no_run
+//! use google_cloud_spanner_admin_database_v1::pagination::DatabaseAdminExt;
+//! # async fn f(admin: impl google_cloud_spanner_admin_database_v1::traits::DatabaseAdmin + Clone + Send + Sync + 'static) -> google_cloud_spanner_admin_database_v1::Result<()> {
+//! let req = google_cloud_spanner_admin_database_v1::model::ListDatabasesRequest::new();
+//! let mut databases = admin.list_databases_stream(req, gax::options::RequestOptions::default());
+//! while let Some(database) = databases.next().await {
+//!     let _database = database?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::traits::DatabaseAdmin;
+use gax::paginator::internal::PageableResponse;
+use gax::paginator::{ItemPaginator, Paginator};
+
+macro_rules! impl_pageable_response {
+    ($response:ty, $item:ty, $field:ident) => {
+        impl PageableResponse for $response {
+            type PageItem = $item;
+            type PageCursor = String;
+
+            fn items(self) -> Vec<Self::PageItem> {
+                self.$field
+            }
+
+            fn next_cursor(&self) -> Option<Self::PageCursor> {
+                if self.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(self.next_page_token.clone())
+                }
+            }
+        }
+    };
+}
+
+impl_pageable_response!(
+    crate::model::ListDatabasesResponse,
+    crate::model::Database,
+    databases
+);
+impl_pageable_response!(
+    crate::model::ListBackupsResponse,
+    crate::model::Backup,
+    backups
+);
+impl_pageable_response!(
+    crate::model::ListDatabaseOperationsResponse,
+    longrunning::model::Operation,
+    operations
+);
+impl_pageable_response!(
+    crate::model::ListBackupOperationsResponse,
+    longrunning::model::Operation,
+    operations
+);
+impl_pageable_response!(
+    crate::model::ListDatabaseRolesResponse,
+    crate::model::DatabaseRole,
+    database_roles
+);
+impl_pageable_response!(
+    crate::model::ListBackupSchedulesResponse,
+    crate::model::BackupSchedule,
+    backup_schedules
+);
+
+/// Adds auto-paginating `*_stream` methods to every [DatabaseAdmin]
+/// implementation.
+///
+/// The `Clone + Send + Sync + 'static` bound lets each stream hold its own
+/// handle to the client so it can keep fetching pages independently of the
+/// caller; it is satisfied by the `Arc`-backed clients this trait is meant
+/// to be used with.
+pub trait DatabaseAdminExt: DatabaseAdmin + Clone + Send + Sync + 'static {
+    /// Lists Cloud Spanner databases, flattening every page into a stream of
+    /// individual [Database][crate::model::Database] values.
+    fn list_databases_stream(
+        &self,
+        req: crate::model::ListDatabasesRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl ItemPaginator<crate::model::ListDatabasesResponse, gax::error::Error> {
+        let client = self.clone();
+        let token = req.page_token.clone();
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { client.list_databases(req, options).await }
+        };
+        gax::paginator::internal::new_paginator(token, execute).items()
+    }
+
+    /// Lists completed and pending backups, flattening every page into a
+    /// stream of individual [Backup][crate::model::Backup] values.
+    fn list_backups_stream(
+        &self,
+        req: crate::model::ListBackupsRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl ItemPaginator<crate::model::ListBackupsResponse, gax::error::Error> {
+        let client = self.clone();
+        let token = req.page_token.clone();
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { client.list_backups(req, options).await }
+        };
+        gax::paginator::internal::new_paginator(token, execute).items()
+    }
+
+    /// Lists database long-running operations, flattening every page into a
+    /// stream of individual [longrunning::model::Operation] values.
+    fn list_database_operations_stream(
+        &self,
+        req: crate::model::ListDatabaseOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl ItemPaginator<crate::model::ListDatabaseOperationsResponse, gax::error::Error> {
+        let client = self.clone();
+        let token = req.page_token.clone();
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { client.list_database_operations(req, options).await }
+        };
+        gax::paginator::internal::new_paginator(token, execute).items()
+    }
+
+    /// Lists backup long-running operations, flattening every page into a
+    /// stream of individual [longrunning::model::Operation] values.
+    fn list_backup_operations_stream(
+        &self,
+        req: crate::model::ListBackupOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl ItemPaginator<crate::model::ListBackupOperationsResponse, gax::error::Error> {
+        let client = self.clone();
+        let token = req.page_token.clone();
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { client.list_backup_operations(req, options).await }
+        };
+        gax::paginator::internal::new_paginator(token, execute).items()
+    }
+
+    /// Lists Cloud Spanner database roles, flattening every page into a
+    /// stream of individual [DatabaseRole][crate::model::DatabaseRole]
+    /// values.
+    fn list_database_roles_stream(
+        &self,
+        req: crate::model::ListDatabaseRolesRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl ItemPaginator<crate::model::ListDatabaseRolesResponse, gax::error::Error> {
+        let client = self.clone();
+        let token = req.page_token.clone();
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { client.list_database_roles(req, options).await }
+        };
+        gax::paginator::internal::new_paginator(token, execute).items()
+    }
+
+    /// Lists the backup schedules for a database, flattening every page into
+    /// a stream of individual
+    /// [BackupSchedule][crate::model::BackupSchedule] values.
+    fn list_backup_schedules_stream(
+        &self,
+        req: crate::model::ListBackupSchedulesRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl ItemPaginator<crate::model::ListBackupSchedulesResponse, gax::error::Error> {
+        let client = self.clone();
+        let token = req.page_token.clone();
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { client.list_backup_schedules(req, options).await }
+        };
+        gax::paginator::internal::new_paginator(token, execute).items()
+    }
+}
+
+impl<T> DatabaseAdminExt for T where T: DatabaseAdmin + Clone + Send + Sync + 'static {}
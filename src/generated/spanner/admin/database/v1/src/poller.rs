@@ -0,0 +1,410 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Progress-reporting pollers for the long-running operations returned by
+//! [DatabaseAdmin][crate::traits::DatabaseAdmin]'s schema- and
+//! backup-mutating RPCs.
+//!
+//! `create_database`, `update_database`, `update_database_ddl`,
+//! `create_backup`, `copy_backup`, and `restore_database` all return a bare
+//! [longrunning::model::Operation] that callers otherwise have to poll,
+//! decode, and pick the embedded
+//! [OperationProgress][crate::model::OperationProgress] out of by hand.
+//! [DatabaseAdminLro] adds an `*_and_poll` method for each of these RPCs
+//! that does this for the caller: it polls the embedded
+//! [Operations][longrunning::traits::Operations] methods using the same
+//! [get_polling_policy][crate::traits::DatabaseAdmin::get_polling_policy]
+//! and
+//! [get_polling_backoff_policy][crate::traits::DatabaseAdmin::get_polling_backoff_policy]
+//! the generated client already exposes, yielding a [PollEvent::Progress]
+//! after every poll and a terminal [PollEvent::Done] once the operation
+//! completes.
+//!
+//! Dropping the returned stream before it yields [PollEvent::Done] cancels
+//! the underlying operation on a best-effort basis (the cancel RPC is
+//! dispatched onto a background task, since `Drop` cannot `.await`),
+//! matching the "Cancelling the returned operation will stop the creation"
+//! semantics documented on `create_backup`, `copy_backup`, and
+//! `restore_database`.
+
+use crate::traits::DatabaseAdmin;
+use futures::Stream;
+use gax::error::Error;
+use gax::loop_state::LoopState;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+/// A snapshot of a long-running operation's progress, decoded from its
+/// metadata's embedded [OperationProgress][crate::model::OperationProgress].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Progress {
+    /// The operation's resource name.
+    pub operation_name: String,
+    /// The percentage of work completed, in the range `0..=100`.
+    pub progress_percent: i32,
+    /// When work began on the operation.
+    pub start_time: Option<wkt::Timestamp>,
+    /// When work finished on the operation, if it has.
+    pub end_time: Option<wkt::Timestamp>,
+}
+
+/// An update yielded while polling a long-running operation. See
+/// [DatabaseAdminLro].
+pub enum PollEvent<R> {
+    /// The operation is still in progress.
+    Progress(Progress),
+    /// The operation has finished, successfully or not. This is always the
+    /// last item the stream yields.
+    Done(crate::Result<R>),
+}
+
+/// Implemented by each RPC's metadata type, all of which embed an
+/// [OperationProgress][crate::model::OperationProgress].
+trait HasProgress {
+    fn progress(&self) -> Option<&crate::model::OperationProgress>;
+}
+
+macro_rules! impl_has_progress {
+    ($metadata:ty) => {
+        impl HasProgress for $metadata {
+            fn progress(&self) -> Option<&crate::model::OperationProgress> {
+                self.progress.as_ref()
+            }
+        }
+    };
+}
+
+impl_has_progress!(crate::model::CreateDatabaseMetadata);
+impl_has_progress!(crate::model::UpdateDatabaseMetadata);
+impl_has_progress!(crate::model::UpdateDatabaseDdlMetadata);
+impl_has_progress!(crate::model::CreateBackupMetadata);
+impl_has_progress!(crate::model::CopyBackupMetadata);
+impl_has_progress!(crate::model::RestoreDatabaseMetadata);
+
+fn decode_progress<M>(operation: &longrunning::model::Operation) -> Option<Progress>
+where
+    M: HasProgress + Default + serde::de::DeserializeOwned,
+{
+    let metadata: M = operation.metadata.as_ref()?.to_msg::<M>().ok()?;
+    let progress = metadata.progress()?;
+    Some(Progress {
+        operation_name: operation.name.clone(),
+        progress_percent: progress.progress_percent,
+        start_time: progress.start_time.clone(),
+        end_time: progress.end_time.clone(),
+    })
+}
+
+pub(crate) fn decode_result<R>(operation: &longrunning::model::Operation) -> crate::Result<R>
+where
+    R: Default + serde::de::DeserializeOwned,
+{
+    match &operation.result {
+        Some(longrunning::model::operation::Result::Response(any)) => {
+            any.to_msg::<R>().map_err(Error::other)
+        }
+        Some(longrunning::model::operation::Result::Error(status)) => Err(Error::other(format!(
+            "operation {} failed: {status:?}",
+            operation.name
+        ))),
+        None => Err(Error::other(format!(
+            "operation {} finished without a result",
+            operation.name
+        ))),
+    }
+}
+
+/// Polls `operation` to completion, yielding a [PollEvent] for every poll.
+/// See the [module][self] docs for the cancellation behavior if this stream
+/// is dropped early.
+fn poll_operation<A, M, R>(
+    admin: A,
+    options: gax::options::RequestOptions,
+    operation: longrunning::model::Operation,
+) -> impl Stream<Item = PollEvent<R>>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+    M: HasProgress + Default + serde::de::DeserializeOwned + Send + 'static,
+    R: Default + serde::de::DeserializeOwned + Send + 'static,
+{
+    let completed = Arc::new(AtomicBool::new(false));
+    let operation_name = operation.name.clone();
+    let polling_policy = admin.get_polling_policy(&options);
+    let backoff_policy = admin.get_polling_backoff_policy(&options);
+    let loop_start = Instant::now();
+
+    enum State {
+        Initial(longrunning::model::Operation),
+        Polling { attempt: u32 },
+        Done,
+    }
+
+    let stream = futures::stream::unfold(
+        (State::Initial(operation), admin.clone(), options.clone()),
+        move |(state, admin, options)| {
+            let polling_policy = polling_policy.clone();
+            let backoff_policy = backoff_policy.clone();
+            async move {
+                match state {
+                    State::Done => None,
+                    State::Initial(op) => {
+                        if op.done {
+                            let result = decode_result::<R>(&op);
+                            return Some((PollEvent::Done(result), (State::Done, admin, options)));
+                        }
+                        let progress = decode_progress::<M>(&op).unwrap_or(Progress {
+                            operation_name: op.name.clone(),
+                            ..Default::default()
+                        });
+                        Some((
+                            PollEvent::Progress(progress),
+                            (State::Polling { attempt: 0 }, admin, options),
+                        ))
+                    }
+                    State::Polling { mut attempt } => loop {
+                        attempt += 1;
+                        let wait = backoff_policy.wait_period(loop_start, attempt);
+                        gax::polling_trace::trace_attempt(&operation_name, attempt, wait);
+                        tokio::time::sleep(wait).await;
+                        let req = longrunning::model::GetOperationRequest::new()
+                            .set_name(operation_name.clone());
+                        match admin.get_operation(req, options.clone()).await {
+                            Ok(op) => {
+                                if op.done {
+                                    let result = decode_result::<R>(&op);
+                                    break Some((
+                                        PollEvent::Done(result),
+                                        (State::Done, admin, options),
+                                    ));
+                                } else {
+                                    let progress =
+                                        decode_progress::<M>(&op).unwrap_or(Progress {
+                                            operation_name: op.name.clone(),
+                                            ..Default::default()
+                                        });
+                                    break Some((
+                                        PollEvent::Progress(progress),
+                                        (State::Polling { attempt }, admin, options),
+                                    ));
+                                }
+                            }
+                            Err(error) => {
+                                let decision = polling_policy.on_error(loop_start, attempt, error);
+                                gax::polling_trace::trace_decision(
+                                    &operation_name,
+                                    attempt,
+                                    &decision,
+                                );
+                                match decision {
+                                    LoopState::Continue(_) => continue,
+                                    LoopState::Exhausted(error) | LoopState::Permanent(error) => {
+                                        break Some((
+                                            PollEvent::Done(Err(error)),
+                                            (State::Done, admin, options),
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        },
+    );
+
+    CancelOnDrop {
+        inner: Box::pin(stream),
+        admin,
+        options,
+        operation_name: operation_name.clone(),
+        completed,
+    }
+}
+
+/// A [Stream] adapter that cancels the wrapped operation if it is dropped
+/// before observing [PollEvent::Done].
+struct CancelOnDrop<A, R> {
+    inner: Pin<Box<dyn Stream<Item = PollEvent<R>> + Send>>,
+    admin: A,
+    options: gax::options::RequestOptions,
+    operation_name: String,
+    completed: Arc<AtomicBool>,
+}
+
+impl<A, R> Stream for CancelOnDrop<A, R>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+    R: Send + 'static,
+{
+    type Item = PollEvent<R>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let next = self.inner.as_mut().poll_next(cx);
+        if let Poll::Ready(Some(PollEvent::Done(_))) = &next {
+            self.completed.store(true, Ordering::Relaxed);
+        }
+        next
+    }
+}
+
+impl<A, R> Drop for CancelOnDrop<A, R>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        if self.completed.load(Ordering::Relaxed) {
+            return;
+        }
+        let admin = self.admin.clone();
+        let options = self.options.clone();
+        let req =
+            longrunning::model::CancelOperationRequest::new().set_name(self.operation_name.clone());
+        tokio::spawn(async move {
+            let _ = admin.cancel_operation(req, options).await;
+        });
+    }
+}
+
+/// Adds progress-reporting `*_and_poll` methods to every [DatabaseAdmin]
+/// implementation, for the RPCs that return a
+/// [longrunning::model::Operation].
+pub trait DatabaseAdminLro: DatabaseAdmin + Clone + Send + Sync + 'static {
+    /// Creates a database, polling until the operation completes.
+    fn create_database_and_poll(
+        &self,
+        req: crate::model::CreateDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl Stream<Item = PollEvent<crate::model::Database>> + Send {
+        poll_create::<Self, crate::model::CreateDatabaseMetadata, crate::model::Database, _>(
+            self.clone(),
+            options,
+            move |admin, req, options| async move { admin.create_database(req, options).await },
+            req,
+        )
+    }
+
+    /// Updates a database, polling until the operation completes.
+    fn update_database_and_poll(
+        &self,
+        req: crate::model::UpdateDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl Stream<Item = PollEvent<crate::model::Database>> + Send {
+        poll_create::<Self, crate::model::UpdateDatabaseMetadata, crate::model::Database, _>(
+            self.clone(),
+            options,
+            move |admin, req, options| async move { admin.update_database(req, options).await },
+            req,
+        )
+    }
+
+    /// Updates a database's DDL, polling until the operation completes. The
+    /// operation has no response, so [PollEvent::Done] always carries
+    /// [wkt::Empty] on success.
+    fn update_database_ddl_and_poll(
+        &self,
+        req: crate::model::UpdateDatabaseDdlRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl Stream<Item = PollEvent<wkt::Empty>> + Send {
+        poll_create::<Self, crate::model::UpdateDatabaseDdlMetadata, wkt::Empty, _>(
+            self.clone(),
+            options,
+            move |admin, req, options| async move { admin.update_database_ddl(req, options).await },
+            req,
+        )
+    }
+
+    /// Creates a backup, polling until the operation completes. Dropping the
+    /// returned stream before it completes stops the creation and deletes
+    /// the backup.
+    fn create_backup_and_poll(
+        &self,
+        req: crate::model::CreateBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl Stream<Item = PollEvent<crate::model::Backup>> + Send {
+        poll_create::<Self, crate::model::CreateBackupMetadata, crate::model::Backup, _>(
+            self.clone(),
+            options,
+            move |admin, req, options| async move { admin.create_backup(req, options).await },
+            req,
+        )
+    }
+
+    /// Copies a backup, polling until the operation completes. Dropping the
+    /// returned stream before it completes stops the copy and deletes the
+    /// destination backup.
+    fn copy_backup_and_poll(
+        &self,
+        req: crate::model::CopyBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl Stream<Item = PollEvent<crate::model::Backup>> + Send {
+        poll_create::<Self, crate::model::CopyBackupMetadata, crate::model::Backup, _>(
+            self.clone(),
+            options,
+            move |admin, req, options| async move { admin.copy_backup(req, options).await },
+            req,
+        )
+    }
+
+    /// Restores a database from a backup, polling until the operation
+    /// completes. Dropping the returned stream before it completes stops the
+    /// restore and deletes the database.
+    fn restore_database_and_poll(
+        &self,
+        req: crate::model::RestoreDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> impl Stream<Item = PollEvent<crate::model::Database>> + Send {
+        poll_create::<Self, crate::model::RestoreDatabaseMetadata, crate::model::Database, _>(
+            self.clone(),
+            options,
+            move |admin, req, options| async move { admin.restore_database(req, options).await },
+            req,
+        )
+    }
+}
+
+impl<T> DatabaseAdminLro for T where T: DatabaseAdmin + Clone + Send + Sync + 'static {}
+
+/// Issues the initial RPC via `start`, then hands the returned operation off
+/// to [poll_operation]. Generic over the RPC's request/metadata/response
+/// types so [DatabaseAdminLro]'s six methods only need to supply the
+/// type-specific bits.
+fn poll_create<A, M, R, Req, F, Fut>(
+    admin: A,
+    options: gax::options::RequestOptions,
+    start: F,
+    req: Req,
+) -> impl Stream<Item = PollEvent<R>>
+where
+    A: DatabaseAdmin + Clone + Send + Sync + 'static,
+    M: HasProgress + Default + serde::de::DeserializeOwned + Send + 'static,
+    R: Default + serde::de::DeserializeOwned + Send + 'static,
+    Req: Send + 'static,
+    F: FnOnce(A, Req, gax::options::RequestOptions) -> Fut + Send + 'static,
+    Fut: std::future::Future<Output = crate::Result<longrunning::model::Operation>> + Send + 'static,
+{
+    use futures::StreamExt;
+    let admin_for_start = admin.clone();
+    let options_for_start = options.clone();
+    futures::stream::once(async move { start(admin_for_start, req, options_for_start).await }).flat_map(
+        move |result| match result {
+            Ok(operation) => {
+                poll_operation::<A, M, R>(admin.clone(), options.clone(), operation).left_stream()
+            }
+            Err(error) => futures::stream::iter(vec![PollEvent::Done(Err(error))]).right_stream(),
+        },
+    )
+}
@@ -0,0 +1,362 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [DatabaseAdmin][crate::traits::DatabaseAdmin] decorator that enforces
+//! [gax::message_size::MessageSizeLimits].
+//!
+//! Spanner DDL bodies and large `ListBackupsResponse` /
+//! `ListDatabaseOperationsResponse` payloads can exceed the default 4MiB
+//! gRPC message cap, which otherwise surfaces as an opaque transport decode
+//! failure. [SizeLimited] checks the request and response of the RPCs most
+//! likely to hit that cap and, when one does, returns a
+//! [gax::message_size::MessageTooLarge] (via [gax::error::Error::other])
+//! that names the RPC and the configured limit.
+//!
+//! There is no transport in this tree to measure the actual wire-encoded
+//! size against, so the check here approximates it with the request's or
+//! response's serialized JSON length. This is enough to catch payloads that
+//! are grossly over the limit and to give callers an actionable error; a
+//! real transport should instead enforce these limits against the true
+//! protobuf-encoded size as it streams bytes on or off the wire.
+
+use crate::traits::DatabaseAdmin;
+use gax::error::Error;
+use gax::message_size::{Direction, MessageSizeLimits};
+
+fn approximate_size<T: serde::Serialize>(value: &T) -> usize {
+    serde_json::to_vec(value).map(|bytes| bytes.len()).unwrap_or(0)
+}
+
+/// Implements a [DatabaseAdmin](crate::traits::DatabaseAdmin) decorator that
+/// enforces [MessageSizeLimits] on the RPCs most likely to exceed them.
+#[derive(Clone, Debug)]
+pub struct SizeLimited<T>
+where
+    T: DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    inner: T,
+    limits: MessageSizeLimits,
+}
+
+impl<T> SizeLimited<T>
+where
+    T: DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    /// Creates a new decorator enforcing the default
+    /// [MessageSizeLimits].
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            limits: MessageSizeLimits::default(),
+        }
+    }
+
+    /// Creates a new decorator enforcing `limits`.
+    pub fn new_with_limits(inner: T, limits: MessageSizeLimits) -> Self {
+        Self { inner, limits }
+    }
+}
+
+impl<T> DatabaseAdmin for SizeLimited<T>
+where
+    T: DatabaseAdmin + std::fmt::Debug + Send + Sync,
+{
+    async fn list_databases(
+        &self,
+        req: crate::model::ListDatabasesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabasesResponse> {
+        self.inner.list_databases(req, options).await
+    }
+
+    async fn create_database(
+        &self,
+        req: crate::model::CreateDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.create_database(req, options).await
+    }
+
+    async fn get_database(
+        &self,
+        req: crate::model::GetDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Database> {
+        self.inner.get_database(req, options).await
+    }
+
+    async fn update_database(
+        &self,
+        req: crate::model::UpdateDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.update_database(req, options).await
+    }
+
+    /// Checks the outgoing DDL statement list against
+    /// [max_encoding_message_size][MessageSizeLimits::max_encoding_message_size]
+    /// before dispatch.
+    async fn update_database_ddl(
+        &self,
+        req: crate::model::UpdateDatabaseDdlRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.limits
+            .check(
+                "DatabaseAdmin.UpdateDatabaseDdl",
+                Direction::Encode,
+                approximate_size(&req),
+            )
+            .map_err(Error::other)?;
+        self.inner.update_database_ddl(req, options).await
+    }
+
+    async fn drop_database(
+        &self,
+        req: crate::model::DropDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.drop_database(req, options).await
+    }
+
+    /// Checks the returned DDL statement list against
+    /// [max_decoding_message_size][MessageSizeLimits::max_decoding_message_size].
+    async fn get_database_ddl(
+        &self,
+        req: crate::model::GetDatabaseDdlRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::GetDatabaseDdlResponse> {
+        let resp = self.inner.get_database_ddl(req, options).await?;
+        self.limits
+            .check(
+                "DatabaseAdmin.GetDatabaseDdl",
+                Direction::Decode,
+                approximate_size(&resp),
+            )
+            .map_err(Error::other)?;
+        Ok(resp)
+    }
+
+    async fn set_iam_policy(
+        &self,
+        req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::Policy> {
+        self.inner.set_iam_policy(req, options).await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::Policy> {
+        self.inner.get_iam_policy(req, options).await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<iam_v1::model::TestIamPermissionsResponse> {
+        self.inner.test_iam_permissions(req, options).await
+    }
+
+    async fn create_backup(
+        &self,
+        req: crate::model::CreateBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.create_backup(req, options).await
+    }
+
+    async fn copy_backup(
+        &self,
+        req: crate::model::CopyBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.copy_backup(req, options).await
+    }
+
+    async fn get_backup(
+        &self,
+        req: crate::model::GetBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Backup> {
+        self.inner.get_backup(req, options).await
+    }
+
+    async fn update_backup(
+        &self,
+        req: crate::model::UpdateBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::Backup> {
+        self.inner.update_backup(req, options).await
+    }
+
+    async fn delete_backup(
+        &self,
+        req: crate::model::DeleteBackupRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.delete_backup(req, options).await
+    }
+
+    /// Checks the returned page against
+    /// [max_decoding_message_size][MessageSizeLimits::max_decoding_message_size]:
+    /// an instance with many backups can return a large response.
+    async fn list_backups(
+        &self,
+        req: crate::model::ListBackupsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupsResponse> {
+        let resp = self.inner.list_backups(req, options).await?;
+        self.limits
+            .check(
+                "DatabaseAdmin.ListBackups",
+                Direction::Decode,
+                approximate_size(&resp),
+            )
+            .map_err(Error::other)?;
+        Ok(resp)
+    }
+
+    async fn restore_database(
+        &self,
+        req: crate::model::RestoreDatabaseRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.restore_database(req, options).await
+    }
+
+    /// Checks the returned page against
+    /// [max_decoding_message_size][MessageSizeLimits::max_decoding_message_size]:
+    /// an instance with many operations can return a large response.
+    async fn list_database_operations(
+        &self,
+        req: crate::model::ListDatabaseOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabaseOperationsResponse> {
+        let resp = self.inner.list_database_operations(req, options).await?;
+        self.limits
+            .check(
+                "DatabaseAdmin.ListDatabaseOperations",
+                Direction::Decode,
+                approximate_size(&resp),
+            )
+            .map_err(Error::other)?;
+        Ok(resp)
+    }
+
+    async fn list_backup_operations(
+        &self,
+        req: crate::model::ListBackupOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupOperationsResponse> {
+        self.inner.list_backup_operations(req, options).await
+    }
+
+    async fn list_database_roles(
+        &self,
+        req: crate::model::ListDatabaseRolesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListDatabaseRolesResponse> {
+        self.inner.list_database_roles(req, options).await
+    }
+
+    async fn create_backup_schedule(
+        &self,
+        req: crate::model::CreateBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.inner.create_backup_schedule(req, options).await
+    }
+
+    async fn get_backup_schedule(
+        &self,
+        req: crate::model::GetBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.inner.get_backup_schedule(req, options).await
+    }
+
+    async fn update_backup_schedule(
+        &self,
+        req: crate::model::UpdateBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::BackupSchedule> {
+        self.inner.update_backup_schedule(req, options).await
+    }
+
+    async fn delete_backup_schedule(
+        &self,
+        req: crate::model::DeleteBackupScheduleRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.delete_backup_schedule(req, options).await
+    }
+
+    async fn list_backup_schedules(
+        &self,
+        req: crate::model::ListBackupSchedulesRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<crate::model::ListBackupSchedulesResponse> {
+        self.inner.list_backup_schedules(req, options).await
+    }
+
+    async fn list_operations(
+        &self,
+        req: longrunning::model::ListOperationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::ListOperationsResponse> {
+        self.inner.list_operations(req, options).await
+    }
+
+    async fn get_operation(
+        &self,
+        req: longrunning::model::GetOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<longrunning::model::Operation> {
+        self.inner.get_operation(req, options).await
+    }
+
+    async fn delete_operation(
+        &self,
+        req: longrunning::model::DeleteOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.delete_operation(req, options).await
+    }
+
+    async fn cancel_operation(
+        &self,
+        req: longrunning::model::CancelOperationRequest,
+        options: gax::options::RequestOptions,
+    ) -> crate::Result<wkt::Empty> {
+        self.inner.cancel_operation(req, options).await
+    }
+
+    fn get_polling_policy(
+        &self,
+        options: &gax::options::RequestOptions,
+    ) -> std::sync::Arc<dyn gax::polling_policy::PollingPolicy> {
+        self.inner.get_polling_policy(options)
+    }
+
+    fn get_polling_backoff_policy(
+        &self,
+        options: &gax::options::RequestOptions,
+    ) -> std::sync::Arc<dyn gax::polling_backoff_policy::PollingBackoffPolicy> {
+        self.inner.get_polling_backoff_policy(options)
+    }
+}